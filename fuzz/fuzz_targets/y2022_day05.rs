@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use advent_of_rust::fuzz;
+use advent_of_rust::y2022::day05::Day05;
+
+fuzz_target!(|data: &[u8]| fuzz::solve(&Day05, data));
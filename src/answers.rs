@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::solution::Answer;
+
+/// Expected answers of the parts of a day for a single input, each of which may be unknown.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct Expected {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<Answer>,
+}
+
+impl Expected {
+    pub fn get(&self, part: u8) -> Option<&Answer> {
+        return if part == 1 { self.part_one.as_ref() } else { self.part_two.as_ref() };
+    }
+
+    fn part_mut(&mut self, part: u8) -> &mut Option<Answer> {
+        return if part == 1 { &mut self.part_one } else { &mut self.part_two };
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Verification {
+    Match,
+    Mismatch(Answer),
+    Missing,
+}
+
+/// Registry of the expected answers, by day, input file and part.
+///
+/// Stored as TOML with a table per day and input file:
+///
+/// ```toml
+/// [day07."input/2022/day07.txt"]
+/// part_one = 95437
+/// part_two = 24933642
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, BTreeMap<String, Expected>>,
+}
+
+impl Answers {
+    pub fn from_toml(toml: &str) -> Result<Answers, String> {
+        return toml::from_str(toml).map_err(|e| e.to_string());
+    }
+
+    pub fn to_toml(&self) -> String {
+        return toml::to_string(self).unwrap();
+    }
+
+    /// Loads the answers from a file, which is treated as empty if it does not exist.
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        return match fs::read_to_string(path) {
+            Ok(toml) => Answers::from_toml(&toml).map_err(|e| format!("Invalid answers file {}: {e}", path.display())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("Unable to read {}: {e}", path.display())),
+        };
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        return fs::create_dir_all(path.parent().unwrap_or(Path::new("")))
+            .and_then(|_| fs::write(path, self.to_toml()))
+            .map_err(|e| format!("Unable to write {}: {e}", path.display()));
+    }
+
+    pub fn expected(&self, day: u8, input: &str, part: u8) -> Option<&Answer> {
+        return self.days.get(&day_key(day))?.get(input)?.get(part);
+    }
+
+    pub fn record(&mut self, day: u8, input: &str, part: u8, answer: Answer) {
+        *self.days
+            .entry(day_key(day))
+            .or_default()
+            .entry(String::from(input))
+            .or_default()
+            .part_mut(part) = Some(answer);
+    }
+
+    pub fn verify(&self, day: u8, input: &str, part: u8, answer: &Answer) -> Verification {
+        return match self.expected(day, input, part) {
+            None => Verification::Missing,
+            Some(expected) if expected == answer => Verification::Match,
+            Some(expected) => Verification::Mismatch(expected.clone()),
+        };
+    }
+}
+
+fn day_key(day: u8) -> String {
+    return format!("day{day:02}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_answers() -> Answers {
+        return Answers::from_toml(&vec![
+            "[day05.\"input/day05.txt\"]",
+            "part_one = \"CMZ\"",
+            "",
+            "[day07.\"input/day07.txt\"]",
+            "part_one = 95437",
+            "part_two = 24933642",
+        ].join("\n")).unwrap();
+    }
+
+    #[test]
+    fn expected() {
+        let answers = example_answers();
+
+        assert_eq!(Some(&Answer::Text(String::from("CMZ"))), answers.expected(5, "input/day05.txt", 1));
+        assert_eq!(Some(&Answer::Number(24933642)), answers.expected(7, "input/day07.txt", 2));
+        assert_eq!(None, answers.expected(5, "input/day05.txt", 2));
+        assert_eq!(None, answers.expected(7, "input/alice/day07.txt", 1));
+    }
+
+    #[test]
+    fn verify() {
+        let answers = example_answers();
+
+        assert_eq!(Verification::Match, answers.verify(7, "input/day07.txt", 1, &Answer::Number(95437)));
+        assert_eq!(
+            Verification::Mismatch(Answer::Number(95437)),
+            answers.verify(7, "input/day07.txt", 1, &Answer::Number(95438))
+        );
+        assert_eq!(Verification::Missing, answers.verify(5, "input/day05.txt", 2, &Answer::from(String::from("MCD"))));
+    }
+
+    #[test]
+    fn record_round_trips_through_toml() {
+        let mut answers = example_answers();
+        answers.record(5, "input/day05.txt", 2, Answer::from(String::from("MCD")));
+        answers.record(10, "input/day10.txt", 2, Answer::from(String::from("##..\n.##.\n")));
+
+        assert_eq!(answers, Answers::from_toml(&answers.to_toml()).unwrap());
+    }
+
+    #[test]
+    fn load_missing_file() {
+        assert_eq!(Ok(Answers::default()), Answers::load(Path::new("does-not-exist.toml")));
+    }
+}
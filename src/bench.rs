@@ -0,0 +1,245 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::solution::{Error, Solution};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// Global allocator counting the allocations made through it.
+///
+/// Has to be installed with `#[global_allocator]` by the binary, otherwise all allocation counts are zero.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size() as u64, Ordering::Relaxed);
+
+        return System.alloc(layout);
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size as u64, Ordering::Relaxed);
+
+        return System.realloc(ptr, layout, new_size);
+    }
+}
+
+fn allocation_counters() -> (u64, u64) {
+    return (ALLOCATIONS.load(Ordering::Relaxed), ALLOCATED_BYTES.load(Ordering::Relaxed));
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Options {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+/// Timings and allocations of a single part, with allocations counted per iteration.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct Measurement {
+    #[serde(with = "nanoseconds")]
+    pub min: Duration,
+    #[serde(with = "nanoseconds")]
+    pub median: Duration,
+    #[serde(with = "nanoseconds")]
+    pub p95: Duration,
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+}
+
+impl Measurement {
+    fn from_samples(mut samples: Vec<Duration>, allocations: u64, allocated_bytes: u64) -> Measurement {
+        samples.sort();
+
+        return Measurement {
+            min: samples[0],
+            median: percentile(&samples, 50),
+            p95: percentile(&samples, 95),
+            allocations,
+            allocated_bytes,
+        };
+    }
+}
+
+/// Nearest-rank percentile of sorted samples.
+fn percentile(sorted: &[Duration], percent: usize) -> Duration {
+    let rank = (percent * sorted.len()).div_ceil(100);
+
+    return sorted[rank.max(1) - 1];
+}
+
+/// Runs a part `options.warmup` times without measuring it, then `options.iterations` times measuring each run.
+pub fn measure(solution: &dyn Solution, part: u8, input: &str, options: Options) -> Result<Measurement, Error> {
+    for _ in 0..options.warmup {
+        solution.part(part, input)?;
+    }
+
+    let mut samples = Vec::with_capacity(options.iterations);
+    let (allocations_before, bytes_before) = allocation_counters();
+
+    for _ in 0..options.iterations.max(1) {
+        let start = Instant::now();
+        solution.part(part, input)?;
+        samples.push(start.elapsed());
+    }
+
+    let (allocations_after, bytes_after) = allocation_counters();
+    let iterations = samples.len() as u64;
+
+    return Ok(Measurement::from_samples(
+        samples,
+        (allocations_after - allocations_before) / iterations,
+        (bytes_after - bytes_before) / iterations,
+    ));
+}
+
+/// Saved measurements to compare later runs against, by day and part.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Baseline {
+    days: BTreeMap<String, BTreeMap<String, Measurement>>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Baseline, String> {
+        let toml = fs::read_to_string(path).map_err(|e| format!("Unable to read {}: {e}", path.display()))?;
+
+        return toml::from_str(&toml).map_err(|e| format!("Invalid baseline file {}: {e}", path.display()));
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let toml = toml::to_string(self).unwrap();
+
+        return fs::write(path, toml).map_err(|e| format!("Unable to write {}: {e}", path.display()));
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Measurement> {
+        return self.days.get(&day_key(day))?.get(part_key(part));
+    }
+
+    pub fn insert(&mut self, day: u8, part: u8, measurement: Measurement) {
+        self.days.entry(day_key(day)).or_default().insert(String::from(part_key(part)), measurement);
+    }
+}
+
+fn day_key(day: u8) -> String {
+    return format!("day{day:02}");
+}
+
+fn part_key(part: u8) -> &'static str {
+    return if part == 1 { "part_one" } else { "part_two" };
+}
+
+/// Relative change of the median compared to the baseline, e.g. `0.25` for 25% slower.
+pub fn change(baseline: &Measurement, current: &Measurement) -> f64 {
+    return current.median.as_secs_f64() / baseline.median.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0;
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    return match nanos {
+        0..=999 => format!("{nanos} ns"),
+        1_000..=999_999 => format!("{:.3} µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.3} ms", nanos as f64 / 1e6),
+        _ => format!("{:.3} s", nanos as f64 / 1e9),
+    };
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    return match bytes {
+        0..=1023 => format!("{bytes} B"),
+        1024..=1_048_575 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / 1_048_576.0),
+    };
+}
+
+pub(crate) mod nanoseconds {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        return serializer.serialize_u64(duration.as_nanos() as u64);
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        return Ok(Duration::from_nanos(u64::deserialize(deserializer)?));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn measurement(median: u64) -> Measurement {
+        return Measurement {
+            min: Duration::from_micros(median / 2),
+            median: Duration::from_micros(median),
+            p95: Duration::from_micros(median * 2),
+            allocations: 12,
+            allocated_bytes: 3456,
+        };
+    }
+
+    #[test]
+    fn measurement_from_samples() {
+        let samples = (1..=20).rev().map(Duration::from_millis).collect();
+
+        let result = Measurement::from_samples(samples, 3, 4);
+
+        assert_eq!(Duration::from_millis(1), result.min);
+        assert_eq!(Duration::from_millis(10), result.median);
+        assert_eq!(Duration::from_millis(19), result.p95);
+    }
+
+    #[test]
+    fn baseline_round_trips_through_toml() {
+        let mut baseline = Baseline::default();
+        baseline.insert(7, 1, measurement(100));
+        baseline.insert(11, 2, measurement(2000));
+
+        let result: Baseline = toml::from_str(&toml::to_string(&baseline).unwrap()).unwrap();
+
+        assert_eq!(Some(&measurement(2000)), result.get(11, 2));
+        assert_eq!(None, result.get(11, 1));
+    }
+
+    #[test]
+    fn change_of_median() {
+        assert_eq!(1.0, change(&measurement(100), &measurement(200)));
+        assert_eq!(-0.5, change(&measurement(100), &measurement(50)));
+    }
+
+    #[rstest]
+    #[case(Duration::from_nanos(999), "999 ns")]
+    #[case(Duration::from_nanos(1_500), "1.500 µs")]
+    #[case(Duration::from_micros(12_345), "12.345 ms")]
+    #[case(Duration::from_millis(2_500), "2.500 s")]
+    fn format_duration_test(#[case] duration: Duration, #[case] expected: &str) {
+        assert_eq!(expected, format_duration(duration));
+    }
+
+    #[rstest]
+    #[case(512, "512 B")]
+    #[case(2048, "2.0 KiB")]
+    #[case(3 * 1024 * 1024, "3.0 MiB")]
+    fn format_bytes_test(#[case] bytes: u64, #[case] expected: &str) {
+        assert_eq!(expected, format_bytes(bytes));
+    }
+}
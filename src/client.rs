@@ -0,0 +1,105 @@
+use std::error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use ureq::{Agent, AgentBuilder};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the tool and where to find its maintainers, as asked for by the Advent of Code automation guidelines.
+pub const USER_AGENT: &str = concat!(
+    "advent-of-rust/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/weph/advent-of-rust)"
+);
+
+/// Client of the Advent of Code website, authenticated by the `session` cookie of a logged in user.
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    agent: Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>) -> Client {
+        let agent = AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        return Client { base_url: String::from(base_url.trim_end_matches('/')), session, agent };
+    }
+
+    /// Downloads the puzzle input of a day.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        let request = self.agent.get(&format!("{}/{year}/day/{day}/input", self.base_url))
+            .set("Cookie", &self.cookie()?);
+
+        return Client::body(request.call(), day);
+    }
+
+    /// Submits the answer of a part and returns the response page.
+    pub fn answer(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<String, ClientError> {
+        let request = self.agent.post(&format!("{}/{year}/day/{day}/answer", self.base_url))
+            .set("Cookie", &self.cookie()?);
+
+        return Client::body(request.send_form(&[("level", &part.to_string()), ("answer", answer)]), day);
+    }
+
+    fn cookie(&self) -> Result<String, ClientError> {
+        let session = self.session.as_ref().ok_or(ClientError::MissingSession)?;
+
+        return Ok(format!("session={session}"));
+    }
+
+    fn body(response: Result<ureq::Response, ureq::Error>, day: u8) -> Result<String, ClientError> {
+        return response
+            .map_err(|e| ClientError::from_request(e, day))?
+            .into_string()
+            .map_err(|e| ClientError::Transport(e.to_string()));
+    }
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    /// The server rejected the session cookie.
+    Unauthorized,
+    /// The puzzle of the day is not unlocked yet.
+    NotAvailable(u8),
+    Status(u16),
+    Transport(String),
+    Write(PathBuf, io::Error),
+}
+
+impl ClientError {
+    fn from_request(error: ureq::Error, day: u8) -> ClientError {
+        return match error {
+            ureq::Error::Status(400, _) => ClientError::Unauthorized,
+            ureq::Error::Status(404, _) => ClientError::NotAvailable(day),
+            ureq::Error::Status(status, _) => ClientError::Status(status),
+            ureq::Error::Transport(transport) => ClientError::Transport(transport.to_string()),
+        };
+    }
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        return match self {
+            ClientError::MissingSession => write!(
+                f,
+                "No session token (set AOC_SESSION or add `session = \"...\"` to the config file)"
+            ),
+            ClientError::Unauthorized => write!(f, "The session token was rejected, it may have expired"),
+            ClientError::NotAvailable(day) => write!(f, "The puzzle of day {day} is not available yet"),
+            ClientError::Status(status) => write!(f, "Unexpected response status {status}"),
+            ClientError::Transport(message) => write!(f, "Request failed: {message}"),
+            ClientError::Write(path, error) => write!(f, "Unable to write {}: {error}", path.display()),
+        };
+    }
+}
+
+impl error::Error for ClientError {}
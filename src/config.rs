@@ -0,0 +1,58 @@
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+/// Settings of the `aoc` binary, stored as TOML:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Deserialize)]
+pub struct Config {
+    /// Value of the `session` cookie of adventofcode.com.
+    pub session: Option<String>,
+}
+
+impl Config {
+    pub fn from_toml(toml: &str) -> Result<Config, String> {
+        return toml::from_str(toml).map_err(|e| e.to_string());
+    }
+
+    /// Loads the config from a file, which is treated as empty if it does not exist.
+    pub fn load(path: &Path) -> Result<Config, String> {
+        return match fs::read_to_string(path) {
+            Ok(toml) => Config::from_toml(&toml).map_err(|e| format!("Invalid config file {}: {e}", path.display())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(format!("Unable to read {}: {e}", path.display())),
+        };
+    }
+
+    /// `$XDG_CONFIG_HOME/aoc/config.toml`, falling back to `~/.config/aoc/config.toml`.
+    pub fn default_path() -> Option<PathBuf> {
+        let directory = match env::var_os("XDG_CONFIG_HOME") {
+            Some(directory) if !directory.is_empty() => PathBuf::from(directory),
+            _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
+        };
+
+        return Some(directory.join("aoc").join("config.toml"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn session_from_toml() {
+        assert_eq!(Ok(Some(String::from("abc123"))), Config::from_toml("session = \"abc123\"").map(|c| c.session));
+        assert_eq!(Ok(Config::default()), Config::from_toml(""));
+    }
+
+    #[test]
+    fn load_missing_file() {
+        assert_eq!(Ok(Config::default()), Config::load(Path::new("does-not-exist.toml")));
+    }
+}
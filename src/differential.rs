@@ -0,0 +1,309 @@
+use std::cell::Cell;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+
+use crate::generate::{Generator, Rng};
+use crate::runner::panic_message;
+use crate::solution::{Answer, Error, Solution};
+
+/// Number of candidates tried while shrinking a counterexample. Inputs that only fail when large would take hours to
+/// shrink completely, so those are reported as far as they were shrunk by then.
+const SHRINK_ATTEMPTS: usize = 500;
+
+/// The straightforward implementation of a day along with an optimized one, which must give the same answers for
+/// every input.
+pub struct Pair {
+    pub reference: &'static dyn Solution,
+    pub optimized: &'static dyn Solution,
+}
+
+/// What a part returned for an input, or the message it panicked with.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Outcome {
+    Returned(Result<Answer, Error>),
+    Panicked(String),
+}
+
+impl Outcome {
+    fn of(solution: &dyn Solution, part: u8, input: &str) -> Outcome {
+        return match panic::catch_unwind(AssertUnwindSafe(|| solution.part(part, input))) {
+            Ok(result) => Outcome::Returned(result),
+            Err(payload) => Outcome::Panicked(panic_message(payload.as_ref())),
+        };
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        return match self {
+            Outcome::Returned(Ok(answer)) => write!(f, "{}", answer.inline()),
+            Outcome::Returned(Err(error)) => write!(f, "error \"{error}\""),
+            Outcome::Panicked(message) => write!(f, "panic \"{message}\""),
+        };
+    }
+}
+
+/// Minimal input found for which the implementations of a day disagree.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Counterexample {
+    pub day: u8,
+    pub part: u8,
+    /// Seed and size of the generated input the counterexample was shrunk from.
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    pub reference: Outcome,
+    pub optimized: Outcome,
+}
+
+impl Display for Counterexample {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Day {:02} Part {}: reference gave {}, optimized gave {}",
+            self.day, self.part, self.reference, self.optimized
+        )?;
+        writeln!(f, "Shrunk from the input of seed {} and size {} to:", self.seed, self.size)?;
+
+        return write!(f, "{}", self.input);
+    }
+}
+
+impl Pair {
+    pub fn day(&self) -> u8 {
+        return self.reference.day();
+    }
+
+    /// First part the implementations disagree on for `input`, with the outcome of each.
+    ///
+    /// Invalid inputs have to fail with an error, so a panic of the reference implementation always counts as a
+    /// disagreement, even if the optimized one panics the same way.
+    pub fn disagreement(&self, input: &str) -> Option<(u8, Outcome, Outcome)> {
+        for part in [1, 2] {
+            let reference = Outcome::of(self.reference, part, input);
+            let optimized = Outcome::of(self.optimized, part, input);
+
+            if reference != optimized || matches!(reference, Outcome::Panicked(_)) {
+                return Some((part, reference, optimized));
+            }
+        }
+
+        return None;
+    }
+
+    /// Compares the implementations on the inputs of `generator` for every seed and size, trying sizes in the given
+    /// order.
+    ///
+    /// The first input they disagree on is shrunk, first by generating it with the smallest power of two as size that
+    /// still fails and then by removing lines and characters, see [shrink], for up to [SHRINK_ATTEMPTS] candidates.
+    pub fn check(&self, generator: Generator, seeds: Range<u64>, sizes: &[usize]) -> Result<(), Box<Counterexample>> {
+        for size in sizes {
+            for seed in seeds.clone() {
+                let input = generator(&mut Rng::new(seed), *size);
+
+                if self.disagreement(&input).is_some() {
+                    return Err(Box::new(self.counterexample(generator, seed, *size)));
+                }
+            }
+        }
+
+        return Ok(());
+    }
+
+    fn counterexample(&self, generator: Generator, seed: u64, size: usize) -> Counterexample {
+        // Trying every smaller size would take quadratic time on large inputs
+        let input = [0].into_iter()
+            .chain((0..usize::BITS).map(|power| 1 << power).take_while(|smaller| *smaller < size))
+            .chain([size])
+            .map(|size| generator(&mut Rng::new(seed), size))
+            .find(|input| self.disagreement(input).is_some())
+            .unwrap();
+
+        let input = shrink(&input, SHRINK_ATTEMPTS, |candidate| self.disagreement(candidate).is_some());
+        let (part, reference, optimized) = self.disagreement(&input).unwrap();
+
+        return Counterexample { day: self.day(), part, seed, size, input, reference, optimized };
+    }
+}
+
+/// Removes as many lines as possible from `input`, and then as many characters as possible from the remaining lines,
+/// while `fails` still holds for the result. Gives up once `fails` ran `attempts` times.
+///
+/// Tries to remove large chunks first and halves their size whenever none of them can be removed, so `fails` runs
+/// far less often than once per subset of lines.
+pub fn shrink(input: &str, attempts: usize, fails: impl Fn(&str) -> bool) -> String {
+    let newline = if input.ends_with('\n') { "\n" } else { "" };
+    let text = |lines: &[String]| lines.join("\n") + newline;
+    let attempts = Cell::new(attempts);
+
+    let mut lines: Vec<String> = input.lines().map(String::from).collect();
+    remove_chunks(&mut lines, &attempts, |candidate| fails(&text(candidate)));
+
+    for index in 0..lines.len() {
+        if attempts.get() == 0 {
+            break;
+        }
+
+        let mut chars: Vec<char> = lines[index].chars().collect();
+
+        remove_chunks(&mut chars, &attempts, |candidate| {
+            let mut candidate_lines = lines.clone();
+            candidate_lines[index] = candidate.iter().collect();
+
+            return fails(&text(&candidate_lines));
+        });
+
+        lines[index] = chars.into_iter().collect();
+    }
+
+    return text(&lines);
+}
+
+/// Removes chunks of `items` while `fails` still holds, decrementing `attempts` for every time `fails` runs.
+fn remove_chunks<T: Clone>(items: &mut Vec<T>, attempts: &Cell<usize>, fails: impl Fn(&[T]) -> bool) {
+    let mut chunk = (items.len() / 2).max(1);
+
+    while chunk > 0 && !items.is_empty() {
+        let mut removed = false;
+        let mut start = 0;
+
+        while start < items.len() {
+            if attempts.get() == 0 {
+                return;
+            }
+
+            attempts.set(attempts.get() - 1);
+            let candidate = [&items[..start], &items[(start + chunk).min(items.len())..]].concat();
+
+            if fails(&candidate) {
+                *items = candidate;
+                removed = true;
+            } else {
+                start += chunk;
+            }
+        }
+
+        if !removed {
+            chunk /= 2;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::Puzzle;
+    use crate::y2022::{day01, generate};
+
+    use super::*;
+
+    /// Gets part one wrong as soon as an elf carries three or more items.
+    struct Broken;
+
+    impl Puzzle for Broken {
+        type Input<'a> = <day01::Day01 as Puzzle>::Input<'a>;
+
+        fn day(&self) -> u8 {
+            return 1;
+        }
+
+        fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, crate::parse::ParseError> {
+            return day01::Day01.parse(input);
+        }
+
+        fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+            if input.iter().any(|items| items.len() >= 3) {
+                return Ok(Answer::Number(0));
+            }
+
+            return day01::Day01.part_one(input);
+        }
+
+        fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+            return day01::Day01.part_two(input);
+        }
+    }
+
+    static BROKEN: Pair = Pair { reference: &day01::Day01, optimized: &Broken };
+
+    /// Panics on part two whenever an elf carries nothing.
+    struct Panicking;
+
+    impl Puzzle for Panicking {
+        type Input<'a> = <day01::Day01 as Puzzle>::Input<'a>;
+
+        fn day(&self) -> u8 {
+            return 1;
+        }
+
+        fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, crate::parse::ParseError> {
+            return day01::Day01.parse(input);
+        }
+
+        fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+            return day01::Day01.part_one(input);
+        }
+
+        fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+            assert!(input.iter().all(|items| !items.is_empty()), "an elf carries nothing");
+            return day01::Day01.part_two(input);
+        }
+    }
+
+    #[test]
+    fn shrink_removes_lines_and_characters() {
+        let input = "keep\nabc\nxyz\nfoo\n";
+
+        assert_eq!("x\n", shrink(input, 100, |candidate| candidate.contains('x')));
+        assert_eq!("ab\nz\n", shrink(input, 100, |candidate| candidate.contains("ab") && candidate.contains('z')));
+    }
+
+    #[test]
+    fn shrink_keeps_input_that_can_not_be_shrunk() {
+        assert_eq!("abc", shrink("abc", 100, |candidate| candidate == "abc"));
+    }
+
+    #[test]
+    fn shrink_gives_up_after_the_given_attempts() {
+        let input = "keep\nabc\nxyz\nfoo\n";
+
+        assert_eq!(input, shrink(input, 0, |candidate| candidate.contains('x')));
+        assert_eq!("xyz\nfoo\n", shrink(input, 1, |candidate| candidate.contains('x')));
+    }
+
+    #[test]
+    fn disagreement() {
+        assert_eq!(None, BROKEN.disagreement("1\n2\n\n3"));
+        assert_eq!(
+            Some((1, Outcome::Returned(Ok(Answer::Number(6))), Outcome::Returned(Ok(Answer::Number(0))))),
+            BROKEN.disagreement("1\n2\n3")
+        );
+    }
+
+    #[test]
+    fn panics_of_the_reference_are_disagreements() {
+        let panicked = Outcome::Panicked(String::from("an elf carries nothing"));
+        let input = "1\n\n\n2";
+
+        assert!(Pair { reference: &Panicking, optimized: &Panicking }.disagreement("1\n\n2").is_none());
+        assert_eq!(
+            Some((2, panicked.clone(), Outcome::Returned(Ok(Answer::Number(3))))),
+            Pair { reference: &Panicking, optimized: &day01::Day01 }.disagreement(input)
+        );
+        assert_eq!(
+            Some((2, panicked.clone(), panicked)),
+            Pair { reference: &Panicking, optimized: &Panicking }.disagreement(input)
+        );
+    }
+
+    #[test]
+    fn check_shrinks_to_minimal_counterexample() {
+        let counterexample = BROKEN.check(generate::generator(1).unwrap(), 0..10, &[1, 10]).unwrap_err();
+
+        assert_eq!(1, counterexample.part);
+        assert_eq!(3, counterexample.input.lines().count());
+        assert!(counterexample.input.lines().all(|line| line.len() == 1), "{}", counterexample.input);
+    }
+}
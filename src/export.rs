@@ -0,0 +1,693 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::grid::Grid;
+use crate::visualize::Frame;
+
+pub type Rgb = [u8; 3];
+
+/// Colors of the characters of a picture, characters without a color of their own are drawn in a light gray.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Palette {
+    colors: BTreeMap<char, Rgb>,
+}
+
+const FALLBACK: Rgb = [204, 204, 204];
+
+impl Default for Palette {
+    /// Dark background, bright `#`, red heads and markers and digits as shades of green, going from dark `0` to
+    /// bright `9`.
+    fn default() -> Palette {
+        let mut colors = BTreeMap::from([
+            (' ', [15, 15, 35]),
+            ('.', [15, 15, 35]),
+            ('#', [255, 255, 102]),
+            ('H', [255, 80, 80]),
+            ('X', [255, 80, 80]),
+        ]);
+
+        for digit in 0..=9 {
+            colors.insert(char::from(b'0' + digit), [0, 60 + digit * 21, 0]);
+        }
+
+        return Palette { colors };
+    }
+}
+
+impl Palette {
+    /// The default palette with the colors of a comma separated list of `<char>=<rrggbb>` entries,
+    /// e.g. `#=ffffff,.=000000`.
+    pub fn parse(spec: &str) -> Result<Palette, String> {
+        let mut palette = Palette::default();
+
+        for entry in spec.split(',').filter(|entry| !entry.is_empty()) {
+            let mut chars = entry.chars();
+            let (Some(c), Some('=')) = (chars.next(), chars.next()) else {
+                return Err(format!("Invalid palette entry {entry:?}, expected <char>=<rrggbb>"));
+            };
+
+            let hex = chars.as_str();
+            let hex = hex.strip_prefix('#').unwrap_or(hex);
+
+            if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(format!("Invalid color {hex:?} of {c:?}, expected six hex digits"));
+            }
+
+            let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+            palette.colors.insert(c, [channel(0), channel(2), channel(4)]);
+        }
+
+        return Ok(palette);
+    }
+
+    pub fn color(&self, c: char) -> Rgb {
+        return self.colors.get(&c).copied().unwrap_or(FALLBACK);
+    }
+
+    /// Color of the space around pictures with lines of different lengths.
+    pub fn background(&self) -> Rgb {
+        return self.color(' ');
+    }
+}
+
+/// A picture as a grid of colors, one per cell.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Image {
+    cells: Grid<Rgb>,
+}
+
+impl Image {
+    pub fn from_grid<T>(grid: &Grid<T>, color: impl Fn(&T) -> Rgb) -> Image {
+        let rows = grid.rows().map(|row| row.iter().map(&color).collect()).collect();
+
+        return Image { cells: Grid::from_rows(rows) };
+    }
+
+    /// Colors every character of the picture, padding short lines with the background.
+    pub fn from_picture(picture: &str, palette: &Palette) -> Image {
+        let width = picture.lines().map(|line| line.chars().count()).max().unwrap_or(0);
+        let rows = picture.lines()
+            .map(|line| line.chars().chain(std::iter::repeat(' ')).take(width).collect())
+            .collect();
+
+        return Image::from_grid(&Grid::from_rows(rows), |c| palette.color(*c));
+    }
+
+    pub fn width(&self) -> usize {
+        return self.cells.width();
+    }
+
+    pub fn height(&self) -> usize {
+        return self.cells.height();
+    }
+
+    /// Every cell as a square of `cell_size` pixels, on a canvas of at least `width` by `height` cells.
+    fn pixels(&self, cell_size: usize, width: usize, height: usize, background: Rgb) -> Grid<Rgb> {
+        let mut pixels = Grid::new(width * cell_size, height * cell_size, background);
+
+        for (x, y) in self.cells.positions() {
+            for dy in 0..cell_size {
+                for dx in 0..cell_size {
+                    pixels[(x * cell_size + dx, y * cell_size + dy)] = self.cells[(x, y)];
+                }
+            }
+        }
+
+        return pixels;
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Format {
+    Png,
+    Ppm,
+    Gif,
+    Svg,
+}
+
+impl Format {
+    pub fn from_path(path: &Path) -> Result<Format, String> {
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or_default().to_ascii_lowercase();
+
+        return match extension.as_str() {
+            "png" => Ok(Format::Png),
+            "ppm" => Ok(Format::Ppm),
+            "gif" => Ok(Format::Gif),
+            "svg" => Ok(Format::Svg),
+            _ => Err(format!("Unknown image format of {}, expected .png, .ppm, .gif or .svg", path.display())),
+        };
+    }
+}
+
+pub struct Options {
+    /// Width and height of a cell in pixels.
+    pub cell_size: usize,
+    pub palette: Palette,
+    /// Time between two frames of an animation.
+    pub delay: Duration,
+    /// Index of the frame exported as still, the last one if `None`.
+    pub frame: Option<usize>,
+}
+
+/// Exports all frames as animated GIF, or a single frame as PNG, PPM or SVG still.
+pub fn export(frames: &[Frame], format: Format, options: &Options) -> Result<Vec<u8>, String> {
+    let images: Vec<Image> = frames.iter().map(|frame| Image::from_picture(&frame.picture, &options.palette)).collect();
+
+    if format == Format::Gif {
+        return gif(&images, options.cell_size, options.palette.background(), options.delay);
+    }
+
+    let index = options.frame.unwrap_or(images.len().saturating_sub(1));
+    let image = images.get(index).ok_or_else(|| format!("There is no frame {}, only {}", index + 1, images.len()))?;
+    let background = options.palette.background();
+
+    return Ok(match format {
+        Format::Png => png(image, options.cell_size, background),
+        Format::Ppm => ppm(image, options.cell_size, background),
+        Format::Svg => svg(image, options.cell_size).into_bytes(),
+        Format::Gif => unreachable!(),
+    });
+}
+
+/// Binary portable pixmap (P6).
+pub fn ppm(image: &Image, cell_size: usize, background: Rgb) -> Vec<u8> {
+    let pixels = image.pixels(cell_size, image.width(), image.height(), background);
+    let mut bytes = format!("P6\n{} {}\n255\n", pixels.width(), pixels.height()).into_bytes();
+
+    bytes.extend(pixels.rows().flatten().flatten());
+
+    return bytes;
+}
+
+/// Truecolor PNG, compressed with the fixed Huffman codes of deflate.
+pub fn png(image: &Image, cell_size: usize, background: Rgb) -> Vec<u8> {
+    let pixels = image.pixels(cell_size, image.width(), image.height(), background);
+
+    let mut header = Vec::new();
+    header.extend((pixels.width() as u32).to_be_bytes());
+    header.extend((pixels.height() as u32).to_be_bytes());
+    // 8 bits per channel, RGB, deflate compression, adaptive filtering, no interlace.
+    header.extend([8, 2, 0, 0, 0]);
+
+    let mut scanlines = Vec::with_capacity(pixels.height() * (pixels.width() * 3 + 1));
+
+    for row in pixels.rows() {
+        // Filter type None, the repetition in pictures of cells compresses well enough without filtering.
+        scanlines.push(0);
+        scanlines.extend(row.iter().flatten());
+    }
+
+    let mut bytes = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+    png_chunk(&mut bytes, b"IHDR", &header);
+    png_chunk(&mut bytes, b"IDAT", &zlib(&scanlines));
+    png_chunk(&mut bytes, b"IEND", &[]);
+
+    return bytes;
+}
+
+fn png_chunk(bytes: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    let mut checked = kind.to_vec();
+    checked.extend(data);
+
+    bytes.extend((data.len() as u32).to_be_bytes());
+    bytes.extend(&checked);
+    bytes.extend(crc32(&checked).to_be_bytes());
+}
+
+/// Scalable vector graphic with a rectangle per run of cells of the same color in a row.
+pub fn svg(image: &Image, cell_size: usize) -> String {
+    let (width, height) = (image.width() * cell_size, image.height() * cell_size);
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\" shape-rendering=\"crispEdges\">\n"
+    );
+
+    for (y, row) in image.cells.rows().enumerate() {
+        let mut x = 0;
+
+        while x < row.len() {
+            let run = row[x..].iter().take_while(|color| **color == row[x]).count();
+            let [r, g, b] = row[x];
+
+            writeln!(
+                svg,
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{cell_size}\" fill=\"#{r:02x}{g:02x}{b:02x}\"/>",
+                x * cell_size,
+                y * cell_size,
+                run * cell_size
+            ).unwrap();
+
+            x += run;
+        }
+    }
+
+    svg.push_str("</svg>\n");
+
+    return svg;
+}
+
+/// Animated GIF looping over the images, which are padded with the background to the size of the largest one.
+pub fn gif(images: &[Image], cell_size: usize, background: Rgb, delay: Duration) -> Result<Vec<u8>, String> {
+    let columns = images.iter().map(Image::width).max().unwrap_or(0);
+    let rows = images.iter().map(Image::height).max().unwrap_or(0);
+    let (width, height) = (columns * cell_size, rows * cell_size);
+
+    if width > u16::MAX as usize || height > u16::MAX as usize {
+        return Err(format!("{width}x{height} pixels exceed the maximum size of a GIF"));
+    }
+
+    let frames: Vec<Grid<Rgb>> = images.iter()
+        .map(|image| image.pixels(cell_size, columns, rows, background))
+        .collect();
+
+    let mut colors = vec![background];
+
+    for color in frames.iter().flat_map(|frame| frame.rows().flatten()) {
+        if !colors.contains(color) {
+            colors.push(*color);
+        }
+
+        if colors.len() > 256 {
+            return Err(String::from("A GIF can not have more than 256 colors"));
+        }
+    }
+
+    // The color table has a power of two entries, at least four since the code size of the image data is at least two.
+    let bits = (usize::BITS - (colors.len() - 1).leading_zeros()).max(2);
+    colors.resize(1 << bits, [0, 0, 0]);
+
+    let mut bytes = b"GIF89a".to_vec();
+    bytes.extend((width as u16).to_le_bytes());
+    bytes.extend((height as u16).to_le_bytes());
+    bytes.extend([0xf0 | (bits as u8 - 1), 0, 0]);
+    bytes.extend(colors.iter().flatten());
+
+    if frames.len() > 1 {
+        // Repeat forever.
+        bytes.extend([0x21, 0xff, 0x0b]);
+        bytes.extend(b"NETSCAPE2.0");
+        bytes.extend([0x03, 0x01, 0x00, 0x00, 0x00]);
+    }
+
+    let centiseconds = (delay.as_millis() / 10).clamp(1, u16::MAX as u128) as u16;
+
+    for frame in &frames {
+        bytes.extend([0x21, 0xf9, 0x04, 0x04]);
+        bytes.extend(centiseconds.to_le_bytes());
+        bytes.extend([0x00, 0x00]);
+
+        bytes.push(0x2c);
+        bytes.extend([0, 0, 0, 0]);
+        bytes.extend((width as u16).to_le_bytes());
+        bytes.extend((height as u16).to_le_bytes());
+        bytes.push(0);
+
+        let indices: Vec<u8> = frame.rows()
+            .flatten()
+            .map(|color| colors.iter().position(|c| c == color).unwrap() as u8)
+            .collect();
+
+        bytes.push(bits as u8);
+
+        for block in lzw(&indices, bits as u8).chunks(255) {
+            bytes.push(block.len() as u8);
+            bytes.extend(block);
+        }
+
+        bytes.push(0);
+    }
+
+    bytes.push(0x3b);
+
+    return Ok(bytes);
+}
+
+/// Writes values of a number of bits, least significant bit first, as required by deflate and GIF.
+struct BitWriter {
+    bytes: Vec<u8>,
+    current: u32,
+    count: u32,
+}
+
+impl BitWriter {
+    fn new() -> BitWriter {
+        return BitWriter { bytes: Vec::new(), current: 0, count: 0 };
+    }
+
+    fn write(&mut self, value: u32, bits: u32) {
+        self.current |= value << self.count;
+        self.count += bits;
+
+        while self.count >= 8 {
+            self.bytes.push(self.current as u8);
+            self.current >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    /// Writes a Huffman code, which is stored most significant bit first.
+    fn write_code(&mut self, code: u32, bits: u32) {
+        self.write(code.reverse_bits() >> (32 - bits), bits);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.bytes.push(self.current as u8);
+        }
+
+        return self.bytes;
+    }
+}
+
+/// Variable length LZW codes of the color indices of a GIF image, starting with a clear code and starting over
+/// whenever the table of 4096 codes is full.
+fn lzw(indices: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear = 1u32 << min_code_size;
+    let end = clear + 1;
+    let mut writer = BitWriter::new();
+    let mut table: BTreeMap<(u32, u8), u32> = BTreeMap::new();
+    let mut code_size = min_code_size as u32 + 1;
+    let mut next = end + 1;
+    let mut prefix: Option<u32> = None;
+
+    writer.write(clear, code_size);
+
+    for &index in indices {
+        let Some(code) = prefix else {
+            prefix = Some(index as u32);
+            continue;
+        };
+
+        if let Some(&longer) = table.get(&(code, index)) {
+            prefix = Some(longer);
+            continue;
+        }
+
+        writer.write(code, code_size);
+
+        if next == 4096 {
+            writer.write(clear, code_size);
+            table.clear();
+            code_size = min_code_size as u32 + 1;
+            next = end + 1;
+        } else {
+            table.insert((code, index), next);
+            next += 1;
+
+            if next > 1 << code_size && code_size < 12 {
+                code_size += 1;
+            }
+        }
+
+        prefix = Some(index as u32);
+    }
+
+    if let Some(code) = prefix {
+        writer.write(code, code_size);
+    }
+
+    writer.write(end, code_size);
+
+    return writer.finish();
+}
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u32; 29] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145,
+    8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u32; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13,
+];
+const WINDOW: usize = 32768;
+const MAX_MATCH: usize = 258;
+
+/// Zlib stream of a single deflate block with fixed Huffman codes.
+///
+/// Matches are looked up in a table of the last position of every hashed triple of bytes, which finds the long runs
+/// of repeated pixels and rows of an image without the complexity of a full blown compressor.
+fn zlib(data: &[u8]) -> Vec<u8> {
+    let mut writer = BitWriter::new();
+    let mut last = vec![usize::MAX; 1 << 15];
+    let hash = |i: usize| ((data[i] as usize) << 10 ^ (data[i + 1] as usize) << 5 ^ data[i + 2] as usize) & 0x7fff;
+
+    // Final block, fixed Huffman codes.
+    writer.write(1, 1);
+    writer.write(1, 2);
+
+    let mut i = 0;
+
+    while i < data.len() {
+        let mut length = 0;
+        let mut distance = 0;
+
+        if i + 3 <= data.len() {
+            let candidate = last[hash(i)];
+
+            if candidate != usize::MAX && i - candidate <= WINDOW {
+                length = (0..MAX_MATCH.min(data.len() - i)).take_while(|k| data[candidate + k] == data[i + k]).count();
+                distance = i - candidate;
+            }
+        }
+
+        if length < 3 {
+            if i + 3 <= data.len() {
+                last[hash(i)] = i;
+            }
+
+            literal(&mut writer, data[i] as u32);
+            i += 1;
+            continue;
+        }
+
+        let code = LENGTH_BASE.iter().rposition(|base| *base as usize <= length).unwrap();
+        literal(&mut writer, 257 + code as u32);
+        writer.write((length - LENGTH_BASE[code] as usize) as u32, LENGTH_EXTRA[code]);
+
+        let code = DISTANCE_BASE.iter().rposition(|base| *base as usize <= distance).unwrap();
+        writer.write_code(code as u32, 5);
+        writer.write((distance - DISTANCE_BASE[code] as usize) as u32, DISTANCE_EXTRA[code]);
+
+        for position in i..(i + length).min(data.len().saturating_sub(2)) {
+            last[hash(position)] = position;
+        }
+
+        i += length;
+    }
+
+    literal(&mut writer, 256);
+
+    let mut bytes = vec![0x78, 0x01];
+    bytes.extend(writer.finish());
+    bytes.extend(adler32(data).to_be_bytes());
+
+    return bytes;
+}
+
+/// Writes a literal, length or end of block symbol with the fixed Huffman code of deflate.
+fn literal(writer: &mut BitWriter, symbol: u32) {
+    let (code, bits) = match symbol {
+        0..=143 => (0x30 + symbol, 8),
+        144..=255 => (0x190 + symbol - 144, 9),
+        256..=279 => (symbol - 256, 7),
+        _ => (0xc0 + symbol - 280, 8),
+    };
+
+    writer.write_code(code, bits);
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+
+    for byte in data {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+
+    return b << 16 | a;
+}
+
+const CRC_TABLE: [u32; 256] = crc_table();
+
+const fn crc_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut n = 0;
+
+    while n < 256 {
+        let mut c = n as u32;
+        let mut k = 0;
+
+        while k < 8 {
+            c = if c & 1 == 1 { 0xedb88320 ^ (c >> 1) } else { c >> 1 };
+            k += 1;
+        }
+
+        table[n] = c;
+        n += 1;
+    }
+
+    return table;
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for byte in data {
+        crc = CRC_TABLE[((crc ^ *byte as u32) & 0xff) as usize] ^ (crc >> 8);
+    }
+
+    return !crc;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WHITE: Rgb = [255, 255, 255];
+    const BLACK: Rgb = [0, 0, 0];
+
+    fn checkerboard() -> Image {
+        let grid = Grid::from_rows(vec![vec![true, false], vec![false, true]]);
+
+        return Image::from_grid(&grid, |c| if *c { WHITE } else { BLACK });
+    }
+
+    /// Reads LZW codes the way a GIF decoder does, to check them against the indices they were encoded from.
+    fn lzw_decode(bytes: &[u8], min_code_size: u8) -> Vec<u8> {
+        let clear = 1usize << min_code_size;
+        let mut bits = bytes.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1));
+        let mut table: Vec<Vec<u8>> = Vec::new();
+        let mut code_size = min_code_size as u32 + 1;
+        let mut previous: Option<Vec<u8>> = None;
+        let mut output = Vec::new();
+
+        loop {
+            let code = (0..code_size).map(|i| (bits.next().unwrap() as usize) << i).sum::<usize>();
+
+            if code == clear {
+                table = (0..clear).map(|i| vec![i as u8]).chain([vec![], vec![]]).collect();
+                code_size = min_code_size as u32 + 1;
+                previous = None;
+                continue;
+            }
+
+            if code == clear + 1 {
+                return output;
+            }
+
+            let entry = match (&previous, table.get(code)) {
+                (_, Some(entry)) => entry.clone(),
+                (Some(previous), None) => [previous.clone(), vec![previous[0]]].concat(),
+                (None, None) => panic!("Code {code} is not in the table"),
+            };
+
+            if let Some(previous) = previous {
+                if table.len() < 4096 {
+                    table.push([previous, vec![entry[0]]].concat());
+                }
+            }
+
+            if table.len() == 1 << code_size && code_size < 12 {
+                code_size += 1;
+            }
+
+            output.extend(&entry);
+            previous = Some(entry);
+        }
+    }
+
+    #[test]
+    fn palette() {
+        let palette = Palette::parse("#=#ffffff,.=102030").unwrap();
+
+        assert_eq!(WHITE, palette.color('#'));
+        assert_eq!([16, 32, 48], palette.color('.'));
+        assert_eq!([255, 80, 80], palette.color('H'));
+        assert_eq!(FALLBACK, palette.color('?'));
+        assert!(Palette::parse("#=fff").is_err());
+        assert!(Palette::parse("#ffffff").is_err());
+    }
+
+    #[test]
+    fn picture_with_lines_of_different_length() {
+        let image = Image::from_picture("#\n.#", &Palette::default());
+
+        assert_eq!((2, 2), (image.width(), image.height()));
+        assert_eq!(Palette::default().background(), image.cells[(1, 0)]);
+    }
+
+    #[test]
+    fn ppm_with_cell_size() {
+        let ppm = ppm(&checkerboard(), 2, BLACK);
+
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!([WHITE, WHITE, BLACK, BLACK].concat(), ppm[11..23]);
+        assert_eq!(11 + 4 * 4 * 3, ppm.len());
+    }
+
+    #[test]
+    fn png_chunks() {
+        let png = png(&checkerboard(), 3, BLACK);
+
+        assert_eq!(b"\x89PNG\r\n\x1a\n", &png[..8]);
+        assert_eq!(b"IHDR\0\0\0\x06\0\0\0\x06\x08\x02\0\0\0", &png[12..29]);
+        assert_eq!(b"\0\0\0\0IEND\xae\x42\x60\x82", &png[png.len() - 12..]);
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(0xcbf43926, crc32(b"123456789"));
+        assert_eq!(0x11e60398, adler32(b"Wikipedia"));
+    }
+
+    #[test]
+    fn lzw_round_trip() {
+        let indices: Vec<u8> = (0..20000u32).map(|i| ((i * i) % 7 + i / 3000) as u8 % 8).collect();
+
+        assert_eq!(indices, lzw_decode(&lzw(&indices, 3), 3));
+        assert_eq!(vec![0; 1000], lzw_decode(&lzw(&[0; 1000], 2), 2));
+    }
+
+    #[test]
+    fn gif_frames() {
+        let images = [checkerboard(), Image::from_grid(&Grid::new(1, 1, WHITE), |c| *c)];
+        let gif = gif(&images, 1, BLACK, Duration::from_millis(250)).unwrap();
+
+        assert_eq!(b"GIF89a\x02\0\x02\0\xf1\0\0", &gif[..13]);
+        assert_eq!([BLACK, WHITE, BLACK, BLACK].concat(), gif[13..25]);
+        assert_eq!(2, gif.windows(4).filter(|w| *w == [0x21, 0xf9, 0x04, 0x04]).count());
+        assert_eq!([25, 0], gif[48..50]);
+        assert_eq!(Some(&0x3b), gif.last());
+    }
+
+    #[test]
+    fn svg_runs() {
+        let image = Image::from_picture("##.", &Palette::parse("#=ffffff,.=000000").unwrap());
+
+        assert_eq!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"30\" height=\"10\" viewBox=\"0 0 30 10\" \
+             shape-rendering=\"crispEdges\">\n\
+             <rect x=\"0\" y=\"0\" width=\"20\" height=\"10\" fill=\"#ffffff\"/>\n\
+             <rect x=\"20\" y=\"0\" width=\"10\" height=\"10\" fill=\"#000000\"/>\n\
+             </svg>\n",
+            svg(&image, 10)
+        );
+    }
+
+    #[test]
+    fn still_of_selected_frame() {
+        let frames = [Frame::new("a", "#"), Frame::new("b", "##")];
+        let options = |frame| Options { cell_size: 1, palette: Palette::default(), delay: Duration::ZERO, frame };
+
+        assert!(export(&frames, Format::Ppm, &options(None)).unwrap().starts_with(b"P6\n2 1\n"));
+        assert!(export(&frames, Format::Ppm, &options(Some(0))).unwrap().starts_with(b"P6\n1 1\n"));
+        assert!(export(&frames, Format::Ppm, &options(Some(2))).is_err());
+        assert_eq!(Ok(Format::Gif), Format::from_path(Path::new("day09.GIF")));
+        assert!(Format::from_path(Path::new("day09.jpg")).is_err());
+    }
+}
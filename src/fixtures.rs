@@ -0,0 +1,199 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::answers::Expected;
+use crate::year;
+
+/// Directory of the fixtures, relative to the root of the crate.
+pub const DIRECTORY: &str = "examples";
+
+/// Example input of a day along with its expected answers.
+///
+/// Stored as `examples/YYYY/dayNN/<name>.txt` next to `examples/YYYY/dayNN/<name>.expected`, which contains the
+/// answers as TOML:
+///
+/// ```toml
+/// part_one = 13140
+/// part_two = """
+/// ##..##..
+/// ###...##
+/// """
+/// ```
+///
+/// Parts without an expected answer are not checked.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Fixture {
+    pub year: u16,
+    pub day: u8,
+    pub name: String,
+    pub input: PathBuf,
+    pub expected: PathBuf,
+}
+
+impl Fixture {
+    pub fn read(&self) -> Result<(String, Expected), String> {
+        let input = read(&self.input)?;
+        let expected = toml::from_str(&read(&self.expected)?)
+            .map_err(|e| format!("Invalid expected answers {}: {e}", self.expected.display()))?;
+
+        return Ok((input, expected));
+    }
+
+    /// Runs the parts of the day on the input and describes every answer that differs from the expected one.
+    pub fn check(&self) -> Vec<String> {
+        let label = format!("{}/day{:02}/{}", self.year, self.day, self.name);
+
+        let Some(solution) = year(self.year).and_then(|year| year.solution(self.day)) else {
+            return vec![format!("{label}: day {} of {} is not implemented", self.day, self.year)];
+        };
+
+        let (input, expected) = match self.read() {
+            Ok(fixture) => fixture,
+            Err(message) => return vec![format!("{label}: {message}")],
+        };
+
+        return [1, 2].into_iter()
+            .filter_map(|part| {
+                let expected = expected.get(part)?;
+
+                return match solution.part(part, &input) {
+                    Ok(answer) if &answer == expected => None,
+                    Ok(answer) => {
+                        Some(format!("{label}: part {part} expected {}, got {}", expected.inline(), answer.inline()))
+                    }
+                    Err(error) => Some(format!("{label}: part {part} failed: {error}")),
+                };
+            })
+            .collect();
+    }
+}
+
+/// Finds the fixtures of all years and days in `directory`, ordered by year, day and name.
+///
+/// Fails if an input has no expected answers or the other way round, so a fixture missing a file is never skipped.
+pub fn discover(directory: &Path) -> Result<Vec<Fixture>, String> {
+    let mut fixtures = Vec::new();
+
+    for year_directory in entries(directory)? {
+        let Some(year) = number_of_directory(&year_directory, "") else {
+            continue;
+        };
+
+        discover_year(&year_directory, year, &mut fixtures)?;
+    }
+
+    fixtures.sort_by(|a, b| (a.year, a.day, &a.name).cmp(&(b.year, b.day, &b.name)));
+
+    return Ok(fixtures);
+}
+
+fn discover_year(directory: &Path, year: u16, fixtures: &mut Vec<Fixture>) -> Result<(), String> {
+    for day_directory in entries(directory)? {
+        let Some(day) = number_of_directory(&day_directory, "day") else {
+            continue;
+        };
+
+        for path in entries(&day_directory)? {
+            let (Some(name), Some(extension)) = (path.file_stem(), path.extension()) else {
+                continue;
+            };
+
+            let input = path.with_extension("txt");
+            let expected = path.with_extension("expected");
+
+            match extension.to_str() {
+                Some("txt") if !expected.exists() => {
+                    return Err(format!("{} has no expected answers in {}", input.display(), expected.display()));
+                }
+                Some("expected") if !input.exists() => {
+                    return Err(format!("{} has no input in {}", expected.display(), input.display()));
+                }
+                Some("txt") => {
+                    fixtures.push(Fixture { year, day, name: name.to_string_lossy().into_owned(), input, expected });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    return Ok(());
+}
+
+fn number_of_directory<T: FromStr>(path: &Path, prefix: &str) -> Option<T> {
+    let name = path.file_name()?.to_str()?;
+
+    return name.strip_prefix(prefix)?.parse().ok();
+}
+
+fn entries(directory: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries = fs::read_dir(directory).map_err(|e| format!("Unable to read {}: {e}", directory.display()))?;
+
+    return Ok(entries.filter_map(|entry| Some(entry.ok()?.path())).collect());
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    return fs::read_to_string(path).map_err(|e| format!("Unable to read {}: {e}", path.display()));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    fn fixtures_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let directory = env::temp_dir().join(format!("advent-of-rust-fixtures-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+
+        for (path, content) in files {
+            fs::create_dir_all(directory.join(path).parent().unwrap()).unwrap();
+            fs::write(directory.join(path), content).unwrap();
+        }
+
+        return directory;
+    }
+
+    #[test]
+    fn discover_and_check() {
+        let directory = fixtures_dir("check", &[
+            ("2022/day06/b.txt", "bvwbjplbgvbhsrlpgdmjqwftvncz"),
+            ("2022/day06/b.expected", "part_one = 5\npart_two = 24"),
+            ("2022/day06/a.txt", "mjqjpqmgbljsphdztnvjfqwrcgsmlb"),
+            ("2022/day06/a.expected", "part_one = 7"),
+            ("2022/day02/example.txt", "A Y\nB X\nC W"),
+            ("2022/day02/example.expected", "part_one = 15"),
+            ("2022/README.md", "Not a day"),
+            ("1999/day01/example.txt", "1"),
+            ("1999/day01/example.expected", "part_one = 1"),
+            ("README.md", "Not a year"),
+        ]);
+
+        let fixtures = discover(&directory).unwrap();
+        let problems = fixtures.iter().flat_map(|f| f.check()).collect::<Vec<_>>();
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(
+            vec![(1999, 1, "example"), (2022, 2, "example"), (2022, 6, "a"), (2022, 6, "b")],
+            fixtures.iter().map(|f| (f.year, f.day, f.name.as_str())).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![
+                "1999/day01/example: day 1 of 1999 is not implemented",
+                "2022/day02/example: part 1 failed: expected the player's response X, Y or Z at line 3, column 3",
+                "2022/day06/b: part 2 expected 24, got 23",
+            ],
+            problems
+        );
+    }
+
+    #[test]
+    fn discover_input_without_expected_answers() {
+        let directory = fixtures_dir("missing", &[("2022/day06/a.txt", "abcd")]);
+
+        let result = discover(&directory);
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert!(result.unwrap_err().contains("2022/day06/a.txt has no expected answers"));
+    }
+}
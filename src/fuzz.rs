@@ -0,0 +1,82 @@
+use std::fs;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::path::{Path, PathBuf};
+use std::str;
+
+use crate::runner::panic_message;
+use crate::solution::Solution;
+
+/// Directory of the fuzz targets, relative to the root of the crate.
+pub const DIRECTORY: &str = "fuzz";
+
+/// Parses the input and solves both parts, ignoring answers and errors. This is what every fuzz target runs.
+///
+/// No input may make a day panic: invalid input has to be rejected with an error. Input that is not UTF-8 never
+/// reaches a day, since it is rejected when reading it.
+pub fn solve(solution: &dyn Solution, data: &[u8]) {
+    let Ok(input) = str::from_utf8(data) else {
+        return;
+    };
+
+    for part in [1, 2] {
+        let _ = solution.part(part, input);
+    }
+}
+
+/// Runs [solve] and returns the message of the panic it ran into, if any.
+pub fn panic(solution: &dyn Solution, data: &[u8]) -> Option<String> {
+    return panic::catch_unwind(AssertUnwindSafe(|| solve(solution, data)))
+        .err()
+        .map(|payload| panic_message(payload.as_ref()));
+}
+
+/// Name of the fuzz target of a day, e.g. `y2022_day07`.
+pub fn target(year: u16, day: u8) -> String {
+    return format!("y{year}_day{day:02}");
+}
+
+/// Inputs of a fuzz target in `directory`: the corpus in `corpus/<target>` and the inputs that made it panic before
+/// in `regressions/<target>`, ordered by path.
+pub fn inputs(directory: &Path, target: &str) -> Result<Vec<PathBuf>, String> {
+    let mut inputs = Vec::new();
+
+    for kind in ["corpus", "regressions"] {
+        let directory = directory.join(kind).join(target);
+
+        let Ok(entries) = fs::read_dir(&directory) else {
+            continue;
+        };
+
+        for entry in entries {
+            let path = entry.map_err(|e| format!("Unable to read {}: {e}", directory.display()))?.path();
+
+            if path.is_file() {
+                inputs.push(path);
+            }
+        }
+    }
+
+    inputs.sort();
+
+    return Ok(inputs);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2022::day06;
+
+    use super::*;
+
+    #[test]
+    fn solve_ignores_errors_and_invalid_utf8() {
+        assert_eq!(None, panic(&day06::Day06, b"mjqjpqmgbljsphdztnvjfqwrcgsmlb"));
+        assert_eq!(None, panic(&day06::Day06, b"ABC"));
+        assert_eq!(None, panic(&day06::Day06, &[0xff, 0xfe]));
+    }
+
+    #[test]
+    fn target_of_day() {
+        assert_eq!("y2022_day07", target(2022, 7));
+    }
+}
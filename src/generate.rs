@@ -0,0 +1,80 @@
+/// Small, seedable pseudo random number generator (SplitMix64).
+///
+/// Implemented here rather than taken from a crate, so the same seed yields the same input on every platform and
+/// with every version of the dependencies.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        return Rng { state: seed };
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+
+        return z ^ (z >> 31);
+    }
+
+    /// Random number between `min` and `max`, both inclusive.
+    pub fn between(&mut self, min: i64, max: i64) -> i64 {
+        return min + (self.next_u64() % (max - min + 1) as u64) as i64;
+    }
+
+    /// Random index below `len`, which must not be zero.
+    pub fn index(&mut self, len: usize) -> usize {
+        return (self.next_u64() % len as u64) as usize;
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        return &items[self.index(items.len())];
+    }
+
+    /// Returns `true` with a probability of 1 in `n`.
+    pub fn one_in(&mut self, n: u64) -> bool {
+        return self.next_u64().is_multiple_of(n);
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// Generates a valid puzzle input of a day, with `size` controlling the number of lines, items or cells.
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+/// Picks `len` characters of the alphabet.
+pub fn letters(rng: &mut Rng, alphabet: &[u8], len: usize) -> String {
+    return (0..len).map(|_| *rng.pick(alphabet) as char).collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+
+        assert_eq!((0..10).map(|_| a.next_u64()).collect_vec(), (0..10).map(|_| b.next_u64()).collect_vec());
+        assert_ne!(Rng::new(42).next_u64(), Rng::new(43).next_u64());
+    }
+
+    #[test]
+    fn between_is_inclusive() {
+        let mut rng = Rng::new(7);
+        let values = (0..1000).map(|_| rng.between(-2, 2)).collect_vec();
+
+        assert_eq!(vec![-2, -1, 0, 1, 2], values.iter().copied().sorted().dedup().collect_vec());
+    }
+}
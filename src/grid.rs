@@ -0,0 +1,298 @@
+use std::ops::{Index, IndexMut};
+
+use crate::parse::{Lines, ParseError, Scanner};
+
+/// Position of a cell as `(x, y)`, with `(0, 0)` being the top left corner.
+pub type Position = (usize, usize);
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T> {
+        return Grid { width, height, cells: vec![value; width * height] };
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from a list of rows.
+    ///
+    /// Panics if the rows are not all of the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let width = rows.first().map_or(0, |r| r.len());
+        let height = rows.len();
+
+        if let Some(y) = rows.iter().position(|r| r.len() != width) {
+            panic!("Row {y} has {} cells, expected {width}", rows[y].len());
+        }
+
+        return Grid { width, height, cells: rows.into_iter().flatten().collect() };
+    }
+
+    /// Parses a grid from text with one row per line, mapping every character to a cell.
+    pub fn parse(input: &str, cell: impl Fn(char) -> T) -> Grid<T> {
+        return Grid::from_rows(input.lines().map(|line| line.chars().map(&cell).collect()).collect());
+    }
+
+    /// Parses a grid from text with one row per line, mapping every character to a cell.
+    ///
+    /// Fails if a character can not be mapped, if the rows are not all of the same length or if there are none.
+    pub fn try_parse(input: &str, expected: &str, cell: impl Fn(char) -> Option<T>) -> Result<Grid<T>, ParseError> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+
+        for line in Lines::new(input) {
+            rows.push(Grid::parse_row(line, rows.first().map(Vec::len), expected, &cell)?);
+        }
+
+        if rows.is_empty() {
+            return Err(ParseError::new(1, 1, expected));
+        }
+
+        return Ok(Grid::from_rows(rows));
+    }
+
+    /// Every problem [Grid::try_parse] could stop at, checking the rows against the length of the first line.
+    pub fn check(input: &str, expected: &str, cell: impl Fn(char) -> Option<T>) -> Vec<ParseError> {
+        let mut problems = Vec::new();
+        let mut width = None;
+
+        for line in Lines::new(input) {
+            let length = line.text().chars().count();
+
+            if let Err(problem) = Grid::parse_row(line, width, expected, &cell) {
+                problems.push(problem);
+            }
+
+            width = width.or(Some(length));
+        }
+
+        if width.is_none() {
+            problems.push(ParseError::new(1, 1, expected));
+        }
+
+        return problems;
+    }
+
+    /// Parses a row of `width` cells, or of any number of cells but at least one if the width is not known yet.
+    fn parse_row(
+        mut line: Scanner,
+        width: Option<usize>,
+        expected: &str,
+        cell: &impl Fn(char) -> Option<T>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut row = Vec::new();
+
+        while width.is_none_or(|width| row.len() < width) && !line.is_at_end() {
+            let column = line.column();
+            let value = cell(line.char(expected)?).ok_or_else(|| ParseError::new(line.line(), column, expected))?;
+
+            row.push(value);
+        }
+
+        if row.is_empty() || width.is_some_and(|width| row.len() < width) {
+            return Err(line.error(expected));
+        }
+
+        line.end()?;
+
+        return Ok(row);
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        return x < self.width && y < self.height;
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if !self.contains(position) {
+            return None;
+        }
+
+        return self.cells.get(self.offset(position));
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if !self.contains(position) {
+            return None;
+        }
+
+        let offset = self.offset(position);
+
+        return self.cells.get_mut(offset);
+    }
+
+    /// Sets the value of a cell, returning `false` if the position is outside of the grid.
+    pub fn set(&mut self, position: Position, value: T) -> bool {
+        return match self.get_mut(position) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        };
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        return &self.cells[y * self.width..(y + 1) * self.width];
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        return (0..self.height).map(|y| self.row(y));
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "Column {x} is outside of the grid");
+
+        return self.cells.iter().skip(x).step_by(self.width);
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        return (0..self.width).map(|x| self.column(x));
+    }
+
+    /// All positions of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+
+        return (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)));
+    }
+
+    /// Positions of the horizontally and vertically adjacent cells that are inside the grid.
+    pub fn neighbours(&self, (x, y): Position) -> impl Iterator<Item = Position> + '_ {
+        return [(0, -1), (1, 0), (0, 1), (-1, 0)]
+            .into_iter()
+            .filter_map(move |(dx, dy)| Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?)))
+            .filter(|position| self.contains(*position));
+    }
+
+    /// Renders the grid as text with one line per row.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        return self.rows()
+            .map(|row| row.iter().map(&cell).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n");
+    }
+
+    fn offset(&self, (x, y): Position) -> usize {
+        return y * self.width + x;
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        return self.get(position).unwrap_or_else(|| panic!("Position {position:?} is outside of the grid"));
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        return self.get_mut(position).unwrap_or_else(|| panic!("Position {position:?} is outside of the grid"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn example_grid() -> Grid<char> {
+        return Grid::parse("abc\ndef", |c| c);
+    }
+
+    #[test]
+    fn parse() {
+        let grid = example_grid();
+
+        assert_eq!(3, grid.width());
+        assert_eq!(2, grid.height());
+        assert_eq!('a', grid[(0, 0)]);
+        assert_eq!('f', grid[(2, 1)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Row 1 has 2 cells, expected 3")]
+    fn parse_should_panic_on_rows_of_different_length() {
+        Grid::parse("abc\nde", |c| c);
+    }
+
+    #[rstest]
+    #[case("12\n3x", ParseError::new(2, 2, "a digit"))]
+    #[case("12\n3", ParseError::new(2, 2, "a digit"))]
+    #[case("12\n345", ParseError::new(2, 3, "end of line"))]
+    #[case("12\n\n34", ParseError::new(2, 1, "a digit"))]
+    #[case("", ParseError::new(1, 1, "a digit"))]
+    fn try_parse_should_report_position_of_invalid_cell(#[case] input: &str, #[case] expected: ParseError) {
+        assert_eq!(Err(expected), Grid::try_parse(input, "a digit", |c| c.to_digit(10)));
+    }
+
+    #[test]
+    fn check_should_report_every_invalid_row() {
+        assert_eq!(
+            vec![
+                ParseError::new(1, 2, "a digit"),
+                ParseError::new(2, 3, "a digit"),
+                ParseError::new(4, 4, "end of line"),
+            ],
+            Grid::check("1x3\n12\n456\n7890", "a digit", |c| c.to_digit(10))
+        );
+        assert_eq!(vec![ParseError::new(1, 1, "a digit")], Grid::check("", "a digit", |c| c.to_digit(10)));
+    }
+
+    #[test]
+    fn get_is_bounds_checked() {
+        let grid = example_grid();
+
+        assert_eq!(Some(&'c'), grid.get((2, 0)));
+        assert_eq!(None, grid.get((3, 0)));
+        assert_eq!(None, grid.get((0, 2)));
+    }
+
+    #[test]
+    fn set() {
+        let mut grid = example_grid();
+
+        assert!(grid.set((1, 1), 'x'));
+        assert!(!grid.set((3, 1), 'x'));
+        assert_eq!("abc\ndxf", grid.render(|c| *c));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = example_grid();
+
+        assert_eq!(vec!["abc", "def"], grid.rows().map(|r| r.iter().collect::<String>()).collect_vec());
+        assert_eq!(vec!["ad", "be", "cf"], grid.columns().map(|c| c.collect::<String>()).collect_vec());
+        assert_eq!("fc", grid.column(2).rev().collect::<String>());
+    }
+
+    #[test]
+    fn positions() {
+        assert_eq!(
+            vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)],
+            example_grid().positions().collect_vec()
+        );
+    }
+
+    #[rstest]
+    #[case((0, 0), vec![(1, 0), (0, 1)])]
+    #[case((1, 0), vec![(2, 0), (1, 1), (0, 0)])]
+    #[case((2, 1), vec![(2, 0), (1, 1)])]
+    fn neighbours(#[case] position: Position, #[case] expected: Vec<Position>) {
+        assert_eq!(expected, example_grid().neighbours(position).collect_vec());
+    }
+}
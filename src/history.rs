@@ -0,0 +1,370 @@
+use std::fs;
+use std::fs::OpenOptions;
+use std::io;
+use std::io::{BufRead, ErrorKind, Read, Write};
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::bench::format_duration;
+use crate::solution::{Answer, Run};
+
+/// A run of a part, as appended to the history.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct Entry {
+    /// Time of the run in seconds since the epoch.
+    pub at: u64,
+    /// Commit the solutions were built from, see [commit].
+    pub commit: Option<String>,
+    pub day: u8,
+    pub part: u8,
+    /// Hash of the input, see [input_hash].
+    pub input: String,
+    pub answer: Answer,
+    #[serde(rename = "parse_time_ns", with = "crate::bench::nanoseconds")]
+    pub parse_time: Duration,
+    #[serde(rename = "solve_time_ns", with = "crate::bench::nanoseconds")]
+    pub solve_time: Duration,
+    /// Flags the part was run with that change its answer or time, like `--param rounds=20` or `--stream`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+}
+
+impl Entry {
+    /// Entry of a run on an input with the given hash, see [input_hash].
+    pub fn new(at: u64, commit: Option<String>, day: u8, part: u8, input: String, run: &Run) -> Entry {
+        return Entry {
+            at,
+            commit,
+            day,
+            part,
+            input,
+            answer: run.answer.clone(),
+            parse_time: run.parse_time,
+            solve_time: run.solve_time,
+            mode: None,
+        };
+    }
+
+    pub fn time(&self) -> Duration {
+        return self.parse_time + self.solve_time;
+    }
+}
+
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+
+fn fnv_1a(hash: u64, bytes: &[u8]) -> u64 {
+    return bytes.iter().fold(hash, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x100000001b3));
+}
+
+/// 64 bit FNV-1a hash of an input as hex, which is the same on every platform and with every version of Rust.
+pub fn input_hash(input: &str) -> String {
+    return format!("{:016x}", fnv_1a(FNV_OFFSET, input.as_bytes()));
+}
+
+/// Reader computing the [input_hash] of what is read through it, for inputs that are streamed instead of read first.
+pub struct HashingReader<R> {
+    inner: R,
+    hash: u64,
+}
+
+impl<R: BufRead> HashingReader<R> {
+    pub fn new(inner: R) -> HashingReader<R> {
+        return HashingReader { inner, hash: FNV_OFFSET };
+    }
+
+    /// Hash of the whole input, reading what was not read yet.
+    pub fn finish(mut self) -> io::Result<String> {
+        io::copy(&mut self, &mut io::sink())?;
+
+        return Ok(format!("{:016x}", self.hash));
+    }
+}
+
+impl<R: BufRead> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let length = self.inner.read(buf)?;
+        self.hash = fnv_1a(self.hash, &buf[..length]);
+
+        return Ok(length);
+    }
+}
+
+impl<R: BufRead> BufRead for HashingReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        return self.inner.fill_buf();
+    }
+
+    fn consume(&mut self, amount: usize) {
+        // The buffer is filled already, so this only returns it again
+        if let Ok(buf) = self.inner.fill_buf() {
+            self.hash = fnv_1a(self.hash, &buf[..amount.min(buf.len())]);
+        }
+
+        self.inner.consume(amount);
+    }
+}
+
+/// Abbreviated commit checked out in the current directory, with `-dirty` appended if tracked files are changed, or
+/// `None` outside of a git repository.
+pub fn commit() -> Option<String> {
+    let output = Command::new("git").args(["rev-parse", "--short=12", "HEAD"]).output().ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let commit = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    let dirty = Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .output()
+        .is_ok_and(|output| output.status.success() && !output.stdout.is_empty());
+
+    return Some(if dirty { format!("{commit}-dirty") } else { commit });
+}
+
+/// Appends entries to a history file, one JSON object per line, creating the file and its directory if needed.
+pub fn append(path: &Path, entries: &[Entry]) -> Result<(), String> {
+    if entries.is_empty() {
+        return Ok(());
+    }
+
+    let lines: String = entries.iter().map(|entry| serde_json::to_string(entry).unwrap() + "\n").collect();
+
+    return fs::create_dir_all(path.parent().unwrap_or(Path::new("")))
+        .and_then(|_| OpenOptions::new().create(true).append(true).open(path))
+        .and_then(|mut file| file.write_all(lines.as_bytes()))
+        .map_err(|e| format!("Unable to write {}: {e}", path.display()));
+}
+
+/// Loads the entries of a history file in the order they were appended, none if the file does not exist.
+pub fn load(path: &Path) -> Result<Vec<Entry>, String> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Unable to read {}: {e}", path.display())),
+    };
+
+    return text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            return serde_json::from_str(line)
+                .map_err(|e| format!("Invalid history file {}, line {}: {e}", path.display(), index + 1));
+        })
+        .collect();
+}
+
+/// Date and time of a number of seconds since the epoch in UTC, as `YYYY-MM-DD HH:MM`.
+fn date_time(at: u64) -> String {
+    let (days, seconds) = ((at / 86400) as i64, at % 86400);
+
+    // Converts days since the epoch to a date of the proleptic Gregorian calendar, with years starting in March
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    return format!("{year:04}-{month:02}-{day:02} {:02}:{:02}", seconds / 3600, seconds % 3600 / 60);
+}
+
+/// Runs whose answer differs from the run before them on the same input, paired with that run.
+pub fn answer_changes<'a>(runs: &[&'a Entry]) -> Vec<(&'a Entry, &'a Entry)> {
+    return runs.windows(2)
+        .filter(|pair| pair[0].answer != pair[1].answer)
+        .map(|pair| (pair[0], pair[1]))
+        .collect();
+}
+
+/// Runs of a part on the same input and in the same mode, in the order they were appended.
+struct Series<'a> {
+    part: u8,
+    input: &'a str,
+    mode: Option<&'a str>,
+    runs: Vec<&'a Entry>,
+}
+
+/// Renders the runs of a day, or of one of its parts, per part, input and mode: the latest `limit` runs with the change
+/// of their time since the run before, the fastest and slowest run, and every run that changed the answer.
+pub fn report(entries: &[Entry], day: u8, part: Option<u8>, limit: usize) -> String {
+    let mut series: Vec<Series> = Vec::new();
+
+    for entry in entries.iter().filter(|entry| entry.day == day && part.is_none_or(|part| entry.part == part)) {
+        let mode = entry.mode.as_deref();
+
+        match series.iter_mut().find(|s| s.part == entry.part && s.input == entry.input && s.mode == mode) {
+            Some(series) => series.runs.push(entry),
+            None => series.push(Series { part: entry.part, input: &entry.input, mode, runs: vec![entry] }),
+        }
+    }
+
+    series.sort_by_key(|series| series.part);
+
+    return series.iter().map(|series| series_report(day, series, limit)).collect::<Vec<String>>().join("\n");
+}
+
+fn series_report(day: u8, series: &Series, limit: usize) -> String {
+    let label = if series.part == 1 { "Part One" } else { "Part Two" };
+    let runs = &series.runs;
+    let mode = series.mode.map(|mode| format!(", {mode}")).unwrap_or_default();
+    let mut report = format!("Day {day:02} {label}, input {}{mode}, {} run(s)\n", series.input, runs.len());
+
+    let shown = runs.len().saturating_sub(limit)..runs.len();
+    let commits: Vec<&str> = runs[shown.clone()].iter().map(|run| run.commit.as_deref().unwrap_or("-")).collect();
+    let answers: Vec<String> = runs[shown.clone()].iter().map(|run| run.answer.inline()).collect();
+    let commit_width = commits.iter().map(|commit| commit.len()).max().unwrap_or(0);
+    let answer_width = answers.iter().map(|answer| answer.chars().count()).max().unwrap_or(0);
+
+    for ((index, commit), answer) in shown.zip(commits).zip(answers) {
+        let run = runs[index];
+        let change = match index.checked_sub(1).map(|previous| runs[previous].time().as_secs_f64()) {
+            Some(previous) if previous > 0.0 => {
+                format!("  {:+.0}%", (run.time().as_secs_f64() / previous - 1.0) * 100.0)
+            }
+            _ => String::new(),
+        };
+
+        report += &format!(
+            "  {}  {commit:commit_width$}  {answer:>answer_width$}  {:>12}{change}\n",
+            date_time(run.at),
+            format_duration(run.time()),
+        );
+    }
+
+    let fastest = runs.iter().map(|run| run.time()).min().unwrap();
+    let slowest = runs.iter().map(|run| run.time()).max().unwrap();
+    report += &format!("  Fastest {}, slowest {}\n", format_duration(fastest), format_duration(slowest));
+
+    let changes = answer_changes(runs);
+
+    if changes.is_empty() {
+        report += &format!("  Same answer {} in every run\n", runs[0].answer.inline());
+    }
+
+    for (before, after) in changes {
+        report += &format!(
+            "  Answer changed from {} to {} on {} at {}\n",
+            before.answer.inline(),
+            after.answer.inline(),
+            date_time(after.at),
+            after.commit.as_deref().unwrap_or("an unknown commit"),
+        );
+    }
+
+    return report;
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn entry(at: u64, commit: &str, part: u8, input: &str, answer: i64, micros: u64) -> Entry {
+        return Entry {
+            at,
+            commit: Some(String::from(commit)),
+            day: 7,
+            part,
+            input: String::from(input),
+            answer: Answer::Number(answer),
+            parse_time: Duration::from_micros(micros / 2),
+            solve_time: Duration::from_micros(micros - micros / 2),
+            mode: None,
+        };
+    }
+
+    #[test]
+    fn input_hash_is_fnv_1a() {
+        assert_eq!("cbf29ce484222325", input_hash(""));
+        assert_eq!("af63dc4c8601ec8c", input_hash("a"));
+    }
+
+    #[test]
+    fn hashing_reader_hashes_the_whole_input() {
+        let input = "1000\n2000\n\n3000\n";
+        let mut reader = HashingReader::new(io::BufReader::with_capacity(3, input.as_bytes()));
+
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        let mut rest = [0; 4];
+        reader.read_exact(&mut rest).unwrap();
+
+        assert_eq!("1000\n", line);
+        assert_eq!(input_hash(input), reader.finish().unwrap());
+    }
+
+    #[rstest]
+    #[case(0, "1970-01-01 00:00")]
+    #[case(951782400, "2000-02-29 00:00")]
+    #[case(1670400000, "2022-12-07 08:00")]
+    #[case(1701388799, "2023-11-30 23:59")]
+    fn date_time_test(#[case] at: u64, #[case] expected: &str) {
+        assert_eq!(expected, date_time(at));
+    }
+
+    #[test]
+    fn entries_round_trip_through_file() {
+        let path = std::env::temp_dir().join(format!("advent-of-rust-history-{}/2022.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let first = vec![entry(100, "abc", 1, "1234", 95437, 10)];
+        let second = vec![
+            entry(200, "def", 1, "1234", 95437, 12),
+            Entry { answer: Answer::from(String::from("##..\n..##\n")), ..entry(200, "def", 2, "1234", 0, 8) },
+            Entry { mode: Some(String::from("--stream")), ..entry(300, "def", 1, "1234", 95437, 30) },
+        ];
+
+        assert_eq!(Ok(Vec::new()), load(&path));
+        append(&path, &first).unwrap();
+        append(&path, &second).unwrap();
+
+        assert_eq!(Ok([first, second].concat()), load(&path));
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn report_shows_trend_and_answer_changes() {
+        let entries = vec![
+            entry(1670400000, "aaa", 1, "1234", 95437, 100),
+            entry(1670400000, "aaa", 2, "1234", 24933642, 100),
+            entry(1670403600, "bbb", 1, "1234", 95437, 50),
+            entry(1670407200, "ccc-dirty", 1, "1234", 95438, 75),
+            entry(1670407200, "ccc-dirty", 1, "5678", 1, 75),
+            Entry {
+                mode: Some(String::from("--param small_directory=10")),
+                ..entry(1670407200, "ccc", 1, "1234", 0, 9)
+            },
+        ];
+
+        assert_eq!(
+            concat!(
+                "Day 07 Part One, input 1234, 3 run(s)\n",
+                "  2022-12-07 09:00  bbb        95437     50.000 µs  -50%\n",
+                "  2022-12-07 10:00  ccc-dirty  95438     75.000 µs  +50%\n",
+                "  Fastest 50.000 µs, slowest 100.000 µs\n",
+                "  Answer changed from 95437 to 95438 on 2022-12-07 10:00 at ccc-dirty\n",
+                "\n",
+                "Day 07 Part One, input 5678, 1 run(s)\n",
+                "  2022-12-07 10:00  ccc-dirty  1     75.000 µs\n",
+                "  Fastest 75.000 µs, slowest 75.000 µs\n",
+                "  Same answer 1 in every run\n",
+                "\n",
+                "Day 07 Part One, input 1234, --param small_directory=10, 1 run(s)\n",
+                "  2022-12-07 10:00  ccc  0      9.000 µs\n",
+                "  Fastest 9.000 µs, slowest 9.000 µs\n",
+                "  Same answer 0 in every run\n",
+            ),
+            report(&entries, 7, Some(1), 2)
+        );
+        assert_eq!(4, report(&entries, 7, None, 10).matches("Day 07").count());
+        assert_eq!("", report(&entries, 8, None, 10));
+    }
+}
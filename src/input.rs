@@ -0,0 +1,189 @@
+use std::error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::{BufRead, BufReader, Cursor, ErrorKind, Read};
+use std::path::{Path, PathBuf};
+
+use crate::client::{Client, ClientError};
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InputSource {
+    /// The `dayNN.txt` file in the given directory.
+    Directory(PathBuf),
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl InputSource {
+    /// Creates a source from a command line argument, where `-` stands for stdin.
+    pub fn from_argument(argument: &str) -> InputSource {
+        if argument == "-" {
+            return InputSource::Stdin;
+        }
+
+        return InputSource::File(PathBuf::from(argument));
+    }
+
+    /// Name of the source as shown in error messages.
+    pub fn name(&self, day: u8) -> String {
+        return match self {
+            InputSource::Directory(_) | InputSource::File(_) => self.path(day).unwrap().display().to_string(),
+            InputSource::Stdin => String::from("<stdin>"),
+            InputSource::Inline(_) => String::from("<inline>"),
+        };
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        let result = match self {
+            InputSource::Directory(_) | InputSource::File(_) => fs::read_to_string(self.path(day).unwrap()),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+            InputSource::Inline(input) => Ok(input.clone()),
+        };
+
+        return result.map_err(|error| InputError { source: self.clone(), name: self.name(day), error });
+    }
+
+    /// Opens the input for reading it piece by piece instead of all at once like [InputSource::read].
+    pub fn open(&self, day: u8) -> Result<Box<dyn BufRead>, InputError> {
+        let result: io::Result<Box<dyn BufRead>> = match self {
+            InputSource::Directory(_) | InputSource::File(_) => {
+                File::open(self.path(day).unwrap()).map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+            }
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::Inline(input) => Ok(Box::new(Cursor::new(input.clone().into_bytes()))),
+        };
+
+        return result.map_err(|error| InputError { source: self.clone(), name: self.name(day), error });
+    }
+
+    fn path(&self, day: u8) -> Option<PathBuf> {
+        return match self {
+            InputSource::Directory(directory) => Some(directory.join(format!("day{day:02}.txt"))),
+            InputSource::File(path) => Some(path.clone()),
+            _ => None,
+        };
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    source: InputSource,
+    name: String,
+    error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        return match (&self.source, self.error.kind()) {
+            (InputSource::Directory(_), ErrorKind::NotFound) => write!(
+                f,
+                "Input file {} does not exist (set AOC_INPUT_DIR or pass --input to read it from elsewhere)",
+                self.name
+            ),
+            (_, ErrorKind::NotFound) => write!(f, "Input file {} does not exist", self.name),
+            _ => write!(f, "Unable to read {}: {}", self.name, self.error),
+        };
+    }
+}
+
+impl error::Error for InputError {}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Download {
+    /// The input had been downloaded before and was left untouched.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads the input of a day of `year` to the `dayNN.txt` file in `directory`, unless that file already exists.
+///
+/// Empty files are placeholders created by `aoc new` and are replaced.
+pub fn download(client: &Client, year: u16, day: u8, directory: &Path) -> Result<Download, ClientError> {
+    let path = InputSource::Directory(directory.to_path_buf()).path(day).unwrap();
+
+    if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(Download::Cached(path));
+    }
+
+    let input = client.input(year, day)?;
+
+    // Written to a temporary file first, so an interrupted download never ends up as cached input
+    let partial = path.with_extension("txt.part");
+    fs::create_dir_all(directory)
+        .and_then(|_| fs::write(&partial, input))
+        .and_then(|_| fs::rename(&partial, &path))
+        .map_err(|e| ClientError::Write(path.clone(), e))?;
+
+    return Ok(Download::Downloaded(path));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn from_argument() {
+        assert_eq!(InputSource::Stdin, InputSource::from_argument("-"));
+        assert_eq!(InputSource::File(PathBuf::from("day05.txt")), InputSource::from_argument("day05.txt"));
+    }
+
+    #[test]
+    fn inline() {
+        let source = InputSource::Inline(String::from("A Y"));
+
+        assert_eq!("A Y", source.read(2).unwrap());
+        assert_eq!("<inline>", source.name(2));
+    }
+
+    #[test]
+    fn directory() {
+        let directory = env::temp_dir().join(format!("advent-of-rust-input-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join("day07.txt"), "$ ls").unwrap();
+
+        let result = InputSource::Directory(directory.clone()).read(7);
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!("$ ls", result.unwrap());
+    }
+
+    #[test]
+    fn open() {
+        let mut input = String::new();
+        InputSource::Inline(String::from("A Y\nB X")).open(2).unwrap().read_to_string(&mut input).unwrap();
+
+        assert_eq!("A Y\nB X", input);
+        assert_eq!(
+            "Input file does-not-exist.txt does not exist",
+            InputSource::File(PathBuf::from("does-not-exist.txt")).open(5).err().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn missing_file_in_directory() {
+        let error = InputSource::Directory(PathBuf::from("does-not-exist")).read(5).unwrap_err();
+
+        assert_eq!(
+            "Input file does-not-exist/day05.txt does not exist \
+                (set AOC_INPUT_DIR or pass --input to read it from elsewhere)",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn missing_file() {
+        let error = InputSource::File(PathBuf::from("does-not-exist.txt")).read(5).unwrap_err();
+
+        assert_eq!("Input file does-not-exist.txt does not exist", error.to_string());
+    }
+}
@@ -0,0 +1,47 @@
+use year::Year;
+
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod config;
+pub mod differential;
+pub mod export;
+pub mod fixtures;
+pub mod fuzz;
+pub mod generate;
+pub mod grid;
+pub mod history;
+pub mod input;
+pub mod params;
+pub mod parse;
+pub mod player;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod serve;
+pub mod solution;
+pub mod stream;
+pub mod submit;
+pub mod trace;
+pub mod visualize;
+pub mod watch;
+pub mod year;
+
+pub mod y2022;
+
+static YEARS: [&Year; 1] = [
+    &y2022::YEAR,
+];
+
+pub fn years() -> &'static [&'static Year] {
+    return &YEARS;
+}
+
+pub fn year(year: u16) -> Option<&'static Year> {
+    return YEARS.iter().find(|y| y.year == year).copied();
+}
+
+/// The most recent year, used when no year is given.
+pub fn latest() -> &'static Year {
+    return YEARS[YEARS.len() - 1];
+}
@@ -0,0 +1,1140 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use clap::{Args, Parser, Subcommand};
+
+use advent_of_rust::answers::{Answers, Verification};
+use advent_of_rust::bench;
+use advent_of_rust::bench::{Baseline, CountingAllocator, Measurement};
+use advent_of_rust::client;
+use advent_of_rust::client::Client;
+use advent_of_rust::config::Config;
+use advent_of_rust::export;
+use advent_of_rust::export::Palette;
+use advent_of_rust::history;
+use advent_of_rust::history::{Entry, HashingReader};
+use advent_of_rust::input;
+use advent_of_rust::input::{Download, InputSource};
+use advent_of_rust::params;
+use advent_of_rust::player;
+use advent_of_rust::report;
+use advent_of_rust::report::{Format, Record};
+use advent_of_rust::runner;
+use advent_of_rust::runner::DayReport;
+use advent_of_rust::scaffold;
+use advent_of_rust::serve::{Limits, Server};
+use advent_of_rust::solution::{Answer, Error, Run, Solution};
+use advent_of_rust::submit;
+use advent_of_rust::submit::{Outcome, Submission, Submissions};
+use advent_of_rust::trace;
+use advent_of_rust::watch;
+use advent_of_rust::watch::Snapshot;
+use advent_of_rust::year::Year;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions")]
+struct Cli {
+    /// Year of the puzzles [default: the latest year with solutions]
+    #[arg(long, global = true, env = "AOC_YEAR", value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<u16>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution of a single day or of all days
+    Run {
+        /// Day to run (1-25) or "all"
+        #[arg(value_parser = parse_days)]
+        day: Days,
+
+        /// Only run the given part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Output format of the results
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        /// Number of days to run at once when running all days [default: number of CPUs]
+        #[arg(long, short = 'j', value_name = "N", value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: Option<u16>,
+
+        /// Solve while reading the input instead of reading all of it first, for inputs too large for memory
+        #[arg(long, conflicts_with_all = ["format", "jobs"])]
+        stream: bool,
+
+        /// Change a parameter of the puzzle, like the number of rounds to simulate, for a single day
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = params::assignment, conflicts_with = "stream")]
+        params: Vec<(String, String)>,
+
+        /// Print what the solution does on its way to the answers, like the moves of the crane on day 5
+        #[arg(long, conflicts_with_all = ["stream", "format", "jobs"])]
+        explain: bool,
+
+        /// File to append the answers and timings to [default: history/YYYY.jsonl]
+        #[arg(long, value_name = "PATH", env = "AOC_HISTORY")]
+        history: Option<PathBuf>,
+
+        /// Do not append the answers and timings to the history
+        #[arg(long)]
+        no_history: bool,
+    },
+    /// Show how the answers and timings of a day changed over the runs recorded in the history
+    History {
+        /// Day to show (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Only show the given part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// File the runs were appended to [default: history/YYYY.jsonl]
+        #[arg(long, value_name = "PATH", env = "AOC_HISTORY")]
+        history: Option<PathBuf>,
+
+        /// Number of latest runs to list per part and input
+        #[arg(long, default_value_t = 10)]
+        limit: usize,
+    },
+    /// Compare the answers of a single day or of all days with the expected ones
+    Verify {
+        /// Day to verify (1-25) or "all"
+        #[arg(value_parser = parse_days, default_value = "all")]
+        day: Days,
+
+        /// Only verify the given part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// File containing the expected answers [default: answers/YYYY.toml]
+        #[arg(long, value_name = "PATH")]
+        answers: Option<PathBuf>,
+
+        /// Record the current answers as the expected ones
+        #[arg(long)]
+        record: bool,
+    },
+    /// Report every problem of an input that does not match the format of the day, instead of only the first one
+    Check {
+        /// Day of the input (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// File to check, or "-" for stdin
+        file: String,
+    },
+    /// Measure how long the parts of a single day or of all days take
+    Bench {
+        /// Day to benchmark (1-25) or "all"
+        #[arg(value_parser = parse_days, default_value = "all")]
+        day: Days,
+
+        /// Only benchmark the given part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Number of unmeasured runs before measuring
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+
+        /// Number of measured runs
+        #[arg(long, default_value_t = 10)]
+        iterations: usize,
+
+        /// Compare the results with a baseline saved by --save-baseline
+        #[arg(long, value_name = "PATH")]
+        baseline: Option<PathBuf>,
+
+        /// Save the results as baseline
+        #[arg(long, value_name = "PATH")]
+        save_baseline: Option<PathBuf>,
+
+        /// Median slowdown in percent above which a part is flagged as regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Download the puzzle input of a day, unless it has been downloaded before
+    Fetch {
+        /// Day to download (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Directory to store the YYYY/dayNN.txt input files in
+        #[arg(long, value_name = "DIR", env = "AOC_INPUT_DIR", default_value = "input")]
+        input_dir: PathBuf,
+
+        #[command(flatten)]
+        session: SessionArgs,
+    },
+    /// Solve a part and submit its answer, unless it is known to be wrong
+    Submit {
+        /// Day to submit (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Part to submit
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// File recording the submitted answers and their outcome [default: submissions/YYYY.toml]
+        #[arg(long, value_name = "PATH")]
+        submissions: Option<PathBuf>,
+
+        #[command(flatten)]
+        session: SessionArgs,
+    },
+    /// Print a random, valid input of a day
+    Generate {
+        /// Day to generate the input of (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Seed of the random number generator, the same seed always yields the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Number of lines, items or cells of the input, depending on the day
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+    },
+    /// Re-run the example tests and the solution of a day whenever its module, input or examples change
+    Watch {
+        /// Day to watch (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Root directory of the crate
+        #[arg(long, value_name = "DIR", default_value = ".")]
+        root: PathBuf,
+
+        /// Directory containing the YYYY/dayNN.txt input files
+        #[arg(long, value_name = "DIR", env = "AOC_INPUT_DIR", default_value = "input")]
+        input_dir: PathBuf,
+
+        /// Milliseconds between two checks for changed files
+        #[arg(long, value_name = "MS", default_value_t = 500)]
+        interval: u64,
+    },
+    /// Play the simulation of a day in the terminal, step by step
+    Viz {
+        /// Day to visualize (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Part to visualize
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2), default_value_t = 1)]
+        part: u8,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Milliseconds between two frames, changed with + and - while playing
+        #[arg(long, value_name = "MS", default_value_t = 100)]
+        delay: u64,
+
+        #[command(flatten)]
+        export: ExportArgs,
+    },
+    /// Create the module of a new day and register it with the runner
+    New {
+        /// Day to create (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Root directory of the crate
+        #[arg(long, value_name = "DIR", default_value = ".")]
+        root: PathBuf,
+
+        /// Directory to create the empty YYYY/dayNN.txt input file in
+        #[arg(long, value_name = "DIR", env = "AOC_INPUT_DIR", default_value = "input")]
+        input_dir: PathBuf,
+    },
+    /// Serve the solutions of every year over HTTP, answering with JSON
+    Serve {
+        /// Address to listen on
+        #[arg(long, value_name = "ADDR", default_value = "127.0.0.1:8080")]
+        address: String,
+
+        /// Largest input accepted, in bytes
+        #[arg(long, value_name = "BYTES", default_value_t = 10 * 1024 * 1024)]
+        max_body: usize,
+
+        /// Seconds a part may take before the request fails
+        #[arg(long, value_name = "SECONDS", default_value_t = 10)]
+        timeout: u64,
+
+        /// Seconds a client has to send its whole request, and then to receive the whole response
+        #[arg(long, value_name = "SECONDS", default_value_t = 10)]
+        request_timeout: u64,
+
+        /// Number of parts solved at once, including those that timed out but are still running [default: number of
+        /// CPUs]
+        #[arg(long, short = 'j', value_name = "N", value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: Option<u16>,
+
+        /// Number of connections answered at once, further ones are answered as busy
+        #[arg(long, value_name = "N", default_value_t = 64, value_parser = clap::value_parser!(u16).range(1..))]
+        max_connections: u16,
+    },
+}
+
+#[derive(Args)]
+struct ExportArgs {
+    /// Write the frames to an image file instead of playing them: an animated .gif or a .png, .ppm or .svg still
+    #[arg(long, short = 'o', value_name = "PATH")]
+    output: Option<PathBuf>,
+
+    /// Width and height of a cell of the picture in pixels
+    #[arg(long, value_name = "PX", default_value_t = 8, value_parser = clap::value_parser!(u16).range(1..))]
+    cell_size: u16,
+
+    /// Colors of characters as comma separated <char>=<rrggbb> entries, e.g. "#=ffffff,.=000000"
+    #[arg(long, value_name = "COLORS", default_value = "")]
+    palette: String,
+
+    /// Frame to export as still, the last one by default
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    frame: Option<u32>,
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Read the input from this file, or from stdin if "-"
+    #[arg(long, value_name = "PATH")]
+    input: Option<String>,
+
+    /// Use the given text as input
+    #[arg(long, value_name = "TEXT", conflicts_with = "input")]
+    input_text: Option<String>,
+
+    /// Directory containing the YYYY/dayNN.txt input files
+    #[arg(long, value_name = "DIR", env = "AOC_INPUT_DIR", default_value = "input")]
+    input_dir: PathBuf,
+}
+
+impl InputArgs {
+    fn source(&self, year: u16) -> InputSource {
+        if let Some(text) = &self.input_text {
+            return InputSource::Inline(text.clone());
+        }
+
+        return match &self.input {
+            Some(argument) => InputSource::from_argument(argument),
+            None => InputSource::Directory(self.input_dir.join(year.to_string())),
+        };
+    }
+}
+
+#[derive(Args)]
+struct SessionArgs {
+    /// Session cookie of adventofcode.com, read from the config file if not given
+    #[arg(long, value_name = "TOKEN", env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+
+    /// Config file containing the session token [default: ~/.config/aoc/config.toml]
+    #[arg(long, value_name = "PATH", env = "AOC_CONFIG")]
+    config: Option<PathBuf>,
+
+    /// Base URL of the Advent of Code website
+    #[arg(long, value_name = "URL", env = "AOC_BASE_URL", default_value = client::BASE_URL, hide = true)]
+    base_url: String,
+}
+
+impl SessionArgs {
+    fn client(&self) -> Result<Client, String> {
+        if self.session.is_some() {
+            return Ok(Client::new(&self.base_url, self.session.clone()));
+        }
+
+        let config = match self.config.clone().or_else(Config::default_path) {
+            Some(path) => Config::load(&path).map_err(|e| format!("error: {e}"))?,
+            None => Config::default(),
+        };
+
+        return Ok(Client::new(&self.base_url, config.session));
+    }
+}
+
+fn puzzles(year: u16) -> Result<&'static Year, String> {
+    return advent_of_rust::year(year).ok_or_else(|| format!("error: There are no solutions for {year}"));
+}
+
+/// File of a year in `directory`, unless another file is given.
+fn file_of_year(path: Option<PathBuf>, directory: &str, year: u16) -> PathBuf {
+    return path.unwrap_or_else(|| Path::new(directory).join(format!("{year}.toml")));
+}
+
+fn history_file(path: Option<PathBuf>, year: u16) -> PathBuf {
+    return path.unwrap_or_else(|| Path::new("history").join(format!("{year}.jsonl")));
+}
+
+#[derive(Clone)]
+enum Days {
+    All,
+    Day(u8),
+}
+
+fn parse_days(value: &str) -> Result<Days, String> {
+    if value == "all" {
+        return Ok(Days::All);
+    }
+
+    return match value.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(Days::Day(day)),
+        _ => Err(format!("expected a day between 1 and 25 or \"all\", got \"{value}\"")),
+    };
+}
+
+fn parts(part: Option<u8>) -> Vec<u8> {
+    return match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+}
+
+fn part_label(part: u8) -> &'static str {
+    return if part == 1 { "Part One" } else { "Part Two" };
+}
+
+fn print_answer(part: u8, answer: &Answer) {
+    match answer {
+        Answer::Text(text) if text.contains('\n') => println!("{}: \n{}", part_label(part), text),
+        _ => println!("{}: {}", part_label(part), answer),
+    }
+}
+
+fn selected_solutions(year: u16, days: &Days, source: &InputSource) -> Result<Vec<&'static dyn Solution>, String> {
+    let puzzles = puzzles(year)?;
+
+    return match days {
+        Days::Day(day) => match puzzles.solution(*day) {
+            Some(solution) => Ok(vec![solution]),
+            None => Err(format!("error: Day {day} of {year} is not implemented")),
+        },
+        Days::All if !matches!(source, InputSource::Directory(_)) => {
+            Err(String::from("error: --input and --input-text can only be used with a single day"))
+        }
+        Days::All => Ok(puzzles.solutions.to_vec()),
+    };
+}
+
+fn error_message(error: Error, solution: &dyn Solution, part: u8, source: &InputSource, input: &str) -> String {
+    return match error {
+        Error::Parse(error) => error.diagnostic(&source.name(solution.day()), input),
+        error => format!("error: {}: {error}", part_label(part)),
+    };
+}
+
+fn solve(solution: &dyn Solution, part: u8, source: &InputSource, input: &str) -> Result<Answer, String> {
+    return solution.part(part, input).map_err(|e| error_message(e, solution, part, source, input));
+}
+
+/// Calls `f`, printing the events it traces as they happen if `explain` is set.
+fn explained<T>(explain: bool, f: impl FnOnce() -> T) -> T {
+    if !explain {
+        return f();
+    }
+
+    return trace::explain(|event| println!("{event}"), f);
+}
+
+/// Appends runs of parts as `(day, part, input hash, run)` to the history, if there is one, tagged with the flags
+/// they were run with, see [Entry::mode]. Only warns if that fails, since the answers are printed already.
+fn record_history<'a>(
+    history: Option<&Path>,
+    mode: Option<&str>,
+    runs: impl IntoIterator<Item = (u8, u8, String, &'a Run)>,
+) {
+    let Some(path) = history else {
+        return;
+    };
+
+    let (at, commit) = (submit::now(), history::commit());
+    let entries: Vec<Entry> = runs.into_iter()
+        .map(|(day, part, input, run)| {
+            return Entry { mode: mode.map(String::from), ..Entry::new(at, commit.clone(), day, part, input, run) };
+        })
+        .collect();
+
+    if let Err(message) = history::append(path, &entries) {
+        eprintln!("warning: {message}");
+    }
+}
+
+fn run_day(
+    solution: &dyn Solution,
+    part: Option<u8>,
+    source: &InputSource,
+    explain: bool,
+    history: Option<&Path>,
+) -> Result<(), String> {
+    let input = source.read(solution.day()).map_err(|e| format!("error: {e}"))?;
+    let hash = history::input_hash(&input);
+    let mut runs = Vec::new();
+
+    for part in parts(part) {
+        let run = explained(explain, || solution.run(part, &input))
+            .map_err(|e| error_message(e, solution, part, source, &input))?;
+
+        print_answer(part, &run.answer);
+        runs.push((part, run));
+    }
+
+    let mode = explain.then_some("--explain");
+    record_history(history, mode, runs.iter().map(|(part, run)| (solution.day(), *part, hash.clone(), run)));
+
+    return Ok(());
+}
+
+/// Runs the selected days in parallel and prints a record per part, failures included, in the given format.
+fn records(
+    selected: &[&dyn Solution],
+    part: Option<u8>,
+    source: &InputSource,
+    format: Format,
+    jobs: usize,
+    history: Option<&Path>,
+) -> Result<(), String> {
+    let reports = run_days(selected, part, source, jobs, history);
+
+    let records: Vec<Record> = reports.iter()
+        .flat_map(|report| {
+            return report.parts.iter().map(|(part, result)| match result {
+                Ok(run) => Record::new(report.day, *part, run.clone()),
+                Err(failure) => Record::failed(report.day, *part, failure),
+            });
+        })
+        .collect();
+
+    // The records go to stdout, so the failures are also reported where the other errors are
+    for report in &reports {
+        for (part, failure) in report.parts.iter().filter_map(|(part, result)| Some((part, result.as_ref().err()?))) {
+            eprintln!("error: Day {:02} Part {part}: {failure}", report.day);
+        }
+    }
+
+    match format {
+        Format::Text => unreachable!("text is printed as a table"),
+        Format::Json => println!("{}", report::json(&records)),
+        Format::Csv => print!("{}", report::csv(&records)),
+    }
+
+    return failures(&reports);
+}
+
+/// Runs all selected days in parallel and prints a single table, so a failing day does not stop the others.
+fn run_all(
+    selected: &[&dyn Solution],
+    part: Option<u8>,
+    source: &InputSource,
+    jobs: usize,
+    history: Option<&Path>,
+) -> Result<(), String> {
+    let start = Instant::now();
+    let reports = run_days(selected, part, source, jobs, history);
+
+    print!("{}", runner::summary(&reports, start.elapsed()));
+
+    return failures(&reports);
+}
+
+/// Runs the selected days on up to `jobs` threads and appends the parts that were solved to the history.
+fn run_days(
+    selected: &[&dyn Solution],
+    part: Option<u8>,
+    source: &InputSource,
+    jobs: usize,
+    history: Option<&Path>,
+) -> Vec<DayReport> {
+    let hashes = Mutex::new(BTreeMap::new());
+    let reports = runner::run_all(
+        selected,
+        &parts(part),
+        |day| {
+            let input = source.read(day).map_err(|e| e.to_string())?;
+            hashes.lock().unwrap().insert(day, history::input_hash(&input));
+            return Ok(input);
+        },
+        jobs,
+    );
+
+    let hashes = hashes.into_inner().unwrap();
+    let runs = reports.iter()
+        .flat_map(|report| report.parts.iter().map(move |(part, result)| (report.day, *part, result)))
+        .filter_map(|(day, part, result)| Some((day, part, hashes.get(&day)?.clone(), result.as_ref().ok()?)));
+    record_history(history, None, runs);
+
+    return reports;
+}
+
+fn failures(reports: &[DayReport]) -> Result<(), String> {
+    let failed: usize = reports.iter().map(DayReport::failures).sum();
+
+    if failed > 0 {
+        return Err(format!("error: {failed} part(s) failed"));
+    }
+
+    return Ok(());
+}
+
+struct RunArgs {
+    format: Format,
+    jobs: Option<u16>,
+    explain: bool,
+    /// File to append the runs to, if any.
+    history: Option<PathBuf>,
+}
+
+fn run(year: u16, days: Days, part: Option<u8>, source: InputSource, args: RunArgs) -> Result<(), String> {
+    let RunArgs { format, jobs, explain, history } = args;
+    let history = history.as_deref();
+
+    if explain && matches!(days, Days::All) {
+        return Err(String::from("error: --explain can only be used with a single day"));
+    }
+
+    let selected = selected_solutions(year, &days, &source)?;
+    let jobs = jobs.map(usize::from)
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+
+    if format != Format::Text {
+        return records(&selected, part, &source, format, jobs, history);
+    }
+
+    if let [solution] = selected[..] {
+        return run_day(solution, part, &source, explain, history);
+    }
+
+    return run_all(&selected, part, &source, jobs, history);
+}
+
+/// Runs a single day with some of its parameters changed, printing all of them along with the answers.
+fn run_with(
+    year: u16,
+    days: Days,
+    part: Option<u8>,
+    source: InputSource,
+    assignments: &[(String, String)],
+    args: RunArgs,
+) -> Result<(), String> {
+    let RunArgs { format, explain, history, .. } = args;
+
+    let Days::Day(day) = days else {
+        return Err(String::from("error: --param can only be used with a single day"));
+    };
+
+    let solution = selected_solutions(year, &days, &source)?[0];
+    let configurable = puzzles(year)?.configurable(day).ok_or_else(|| format!("error: Day {day} has no parameters"))?;
+    let values = configurable.params(assignments).map_err(|e| format!("error: {e}"))?;
+    let input = source.read(day).map_err(|e| format!("error: {e}"))?;
+
+    if format == Format::Text {
+        let values: Vec<String> = values.iter().map(|(name, value)| format!("{name}={value}")).collect();
+        println!("Parameters: {}", values.join(", "));
+    }
+
+    let mut runs = Vec::new();
+
+    for part in parts(part) {
+        let run = explained(explain, || configurable.run_with(part, &input, assignments))
+            .map_err(|e| error_message(e, solution, part, &source, &input))?;
+
+        if format == Format::Text {
+            print_answer(part, &run.answer);
+        }
+
+        runs.push((part, run));
+    }
+
+    let mut mode: Vec<String> = assignments.iter().map(|(name, value)| format!("--param {name}={value}")).collect();
+    mode.extend(explain.then(|| String::from("--explain")));
+
+    let hash = history::input_hash(&input);
+    let entries = runs.iter().map(|(part, run)| (day, *part, hash.clone(), run));
+    record_history(history.as_deref(), Some(&mode.join(" ")), entries);
+
+    let records: Vec<Record> = runs.into_iter().map(|(part, run)| Record::new(day, part, run)).collect();
+
+    match format {
+        Format::Text => {}
+        Format::Json => println!("{}", report::json(&records)),
+        Format::Csv => print!("{}", report::csv(&records)),
+    }
+
+    return Ok(());
+}
+
+/// Solves the parts of a day while reading the input, which is opened once per part.
+fn run_streamed(
+    year: u16,
+    days: Days,
+    part: Option<u8>,
+    source: InputSource,
+    history: Option<&Path>,
+) -> Result<(), String> {
+    let Days::Day(day) = days else {
+        return Err(String::from("error: --stream can only be used with a single day"));
+    };
+
+    let stream = puzzles(year)?.stream(day).ok_or_else(|| format!("error: Day {day} can not be streamed"))?;
+
+    if source == InputSource::Stdin && part.is_none() {
+        return Err(String::from("error: stdin can only be streamed once, pass --part"));
+    }
+
+    for part in parts(part) {
+        let mut input = HashingReader::new(source.open(day).map_err(|e| format!("error: {e}"))?);
+
+        let start = Instant::now();
+        let answer = stream.stream(part, &mut input).map_err(|e| format!("error: {}: {e}", source.name(day)))?;
+        let run = Run { answer, parse_time: Duration::ZERO, solve_time: start.elapsed() };
+
+        print_answer(part, &run.answer);
+
+        let hash = input.finish().map_err(|e| format!("error: {}: {e}", source.name(day)))?;
+        record_history(history, Some("--stream"), [(day, part, hash, &run)]);
+    }
+
+    return Ok(());
+}
+
+fn verify(
+    year: u16,
+    days: Days,
+    part: Option<u8>,
+    source: InputSource,
+    path: PathBuf,
+    record: bool,
+) -> Result<(), String> {
+    let mut answers = Answers::load(&path).map_err(|e| format!("error: {e}"))?;
+    let mut failed = false;
+
+    for solution in selected_solutions(year, &days, &source)? {
+        let day = solution.day();
+        let name = source.name(day);
+
+        let input = match source.read(day) {
+            Ok(input) => input,
+            Err(error) => {
+                println!("Day {day:02}           skipped   {error}");
+                continue;
+            }
+        };
+
+        for part in parts(part) {
+            let label = format!("Day {day:02} {}", part_label(part));
+
+            let answer = match solve(solution, part, &source, &input) {
+                Ok(answer) => answer,
+                Err(message) => {
+                    println!("{label}  error");
+                    eprintln!("{message}");
+                    failed = true;
+                    continue;
+                }
+            };
+
+            match answers.verify(day, &name, part, &answer) {
+                Verification::Match => println!("{label}  ok"),
+                Verification::Missing => println!("{label}  missing   {}", answer.inline()),
+                Verification::Mismatch(expected) => {
+                    println!("{label}  MISMATCH  expected {}, got {}", expected.inline(), answer.inline());
+                    failed = failed || !record;
+                }
+            }
+
+            if record {
+                answers.record(day, &name, part, answer);
+            }
+        }
+    }
+
+    if record {
+        answers.save(&path).map_err(|e| format!("error: {e}"))?;
+        println!("Recorded answers in {}", path.display());
+    }
+
+    if failed {
+        return Err(String::from("error: verification failed"));
+    }
+
+    return Ok(());
+}
+
+fn check(year: u16, day: u8, source: InputSource) -> Result<(), String> {
+    let solution = puzzles(year)?.solution(day)
+        .ok_or_else(|| format!("error: Day {day} of {year} is not implemented"))?;
+    let input = source.read(day).map_err(|e| format!("error: {e}"))?;
+    let problems = solution.check(&input);
+
+    for problem in &problems {
+        println!("{}\n", problem.diagnostic(&source.name(day), &input));
+    }
+
+    if !problems.is_empty() {
+        return Err(format!("error: {} problem(s) found in {}", problems.len(), source.name(day)));
+    }
+
+    println!("{} is a valid input of day {day}", source.name(day));
+
+    return Ok(());
+}
+
+fn show_history(day: u8, part: Option<u8>, path: &Path, limit: usize) -> Result<(), String> {
+    let entries = history::load(path).map_err(|e| format!("error: {e}"))?;
+    let report = history::report(&entries, day, part, limit);
+
+    if report.is_empty() {
+        return Err(format!("error: No runs of day {day} in {}", path.display()));
+    }
+
+    print!("{report}");
+
+    return Ok(());
+}
+
+struct BenchArgs {
+    warmup: usize,
+    iterations: usize,
+    baseline: Option<PathBuf>,
+    save_baseline: Option<PathBuf>,
+    threshold: f64,
+}
+
+fn comparison(baseline: &Baseline, day: u8, part: u8, measurement: &Measurement, threshold: f64) -> (String, bool) {
+    return match baseline.get(day, part) {
+        None => (String::from("no baseline"), false),
+        Some(previous) => {
+            let change = bench::change(previous, measurement) * 100.0;
+            let regression = change > threshold;
+
+            (format!("{change:+.1}%{}", if regression { "  REGRESSION" } else { "" }), regression)
+        }
+    };
+}
+
+fn benchmark(year: u16, days: Days, part: Option<u8>, source: InputSource, args: BenchArgs) -> Result<(), String> {
+    let options = bench::Options { warmup: args.warmup, iterations: args.iterations };
+    let baseline = match &args.baseline {
+        Some(path) => Some(Baseline::load(path).map_err(|e| format!("error: {e}"))?),
+        None => None,
+    };
+
+    let mut results = Baseline::default();
+    let mut regressions = 0;
+
+    println!("{:<16} {:>12} {:>12} {:>12} {:>10} {:>12}", "", "min", "median", "p95", "allocs", "allocated");
+
+    for solution in selected_solutions(year, &days, &source)? {
+        let day = solution.day();
+        let input = source.read(day).map_err(|e| format!("error: {e}"))?;
+
+        for part in parts(part) {
+            let measurement = bench::measure(solution, part, &input, options)
+                .map_err(|e| error_message(e, solution, part, &source, &input))?;
+
+            let mut line = format!(
+                "Day {day:02} {} {:>12} {:>12} {:>12} {:>10} {:>12}",
+                part_label(part),
+                bench::format_duration(measurement.min),
+                bench::format_duration(measurement.median),
+                bench::format_duration(measurement.p95),
+                measurement.allocations,
+                bench::format_bytes(measurement.allocated_bytes),
+            );
+
+            if let Some(baseline) = &baseline {
+                let (text, regression) = comparison(baseline, day, part, &measurement, args.threshold);
+                line.push_str(&format!("  {text}"));
+
+                if regression {
+                    regressions += 1;
+                }
+            }
+
+            println!("{line}");
+            results.insert(day, part, measurement);
+        }
+    }
+
+    if let Some(path) = &args.save_baseline {
+        results.save(path).map_err(|e| format!("error: {e}"))?;
+        println!("Saved baseline to {}", path.display());
+    }
+
+    if regressions > 0 {
+        return Err(format!("error: {regressions} part(s) regressed by more than {}%", args.threshold));
+    }
+
+    return Ok(());
+}
+
+fn fetch(year: u16, day: u8, input_dir: PathBuf, session: SessionArgs) -> Result<(), String> {
+    let client = session.client()?;
+
+    match input::download(&client, year, day, &input_dir.join(year.to_string())).map_err(|e| format!("error: {e}"))? {
+        Download::Cached(path) => println!("{} already exists, not downloading it again", path.display()),
+        Download::Downloaded(path) => println!("Downloaded the input of day {day} to {}", path.display()),
+    }
+
+    return Ok(());
+}
+
+fn submit(
+    year: u16,
+    day: u8,
+    part: u8,
+    source: InputSource,
+    path: PathBuf,
+    session: SessionArgs,
+) -> Result<(), String> {
+    let solution = puzzles(year)?.solution(day)
+        .ok_or_else(|| format!("error: Day {day} of {year} is not implemented"))?;
+    let input = source.read(day).map_err(|e| format!("error: {e}"))?;
+    let answer = solve(solution, part, &source, &input)?;
+
+    if answer.to_string().contains('\n') {
+        return Err(format!(
+            "error: {} spans multiple lines and has to be read and submitted manually",
+            part_label(part)
+        ));
+    }
+
+    let mut submissions = Submissions::load(&path).map_err(|e| format!("error: {e}"))?;
+    submissions.check(day, part, &answer, submit::now()).map_err(|e| format!("error: Not submitting {answer}: {e}"))?;
+
+    let page = session.client()?.answer(year, day, part, &answer.to_string()).map_err(|e| format!("error: {e}"))?;
+    let outcome = Outcome::parse(&page)
+        .ok_or_else(|| String::from("error: Unable to find the outcome in the response"))?;
+
+    submissions.record(day, part, Submission { answer: answer.clone(), outcome, at: submit::now() });
+    submissions.save(&path).map_err(|e| format!("error: {e}"))?;
+
+    println!("Day {day:02} {}: submitted {answer}, {outcome}", part_label(part));
+
+    if outcome != Outcome::Correct {
+        return Err(format!("error: {answer} was not accepted"));
+    }
+
+    return Ok(());
+}
+
+fn generate(year: u16, day: u8, seed: u64, size: usize) -> Result<(), String> {
+    let input = puzzles(year)?.generate(day, seed, size)
+        .ok_or_else(|| format!("error: Day {day} has no input generator"))?;
+    println!("{input}");
+
+    return Ok(());
+}
+
+/// Runs the example tests and solves the day, printing how the answers changed since the `previous` run.
+fn watch_run(
+    root: &Path,
+    input_dir: &Path,
+    year: u16,
+    day: u8,
+    previous: Option<&BTreeMap<u8, Answer>>,
+) -> Option<BTreeMap<u8, Answer>> {
+    match watch::run_tests(root, year, day) {
+        Ok(output) if output.status.success() => println!("Example tests: ok"),
+        Ok(output) => {
+            println!("Example tests: FAILED");
+            print!("{}", String::from_utf8_lossy(&output.stdout));
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        }
+        Err(message) => eprintln!("error: {message}"),
+    }
+
+    let output = match watch::run_solve(root, input_dir, year, day) {
+        Ok(output) => output,
+        Err(message) => {
+            eprintln!("error: {message}");
+            return None;
+        }
+    };
+
+    if !output.status.success() {
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        return None;
+    }
+
+    return match watch::answers_from_json(&String::from_utf8_lossy(&output.stdout)) {
+        Ok(answers) => {
+            for line in watch::diff(previous, &answers) {
+                println!("{line}");
+            }
+
+            Some(answers)
+        }
+        Err(message) => {
+            eprintln!("error: {message}");
+            None
+        }
+    };
+}
+
+fn watch(year: u16, day: u8, root: PathBuf, input_dir: PathBuf, interval: Duration) -> Result<(), String> {
+    // Cargo runs in the root directory, so a relative input directory would be resolved differently there.
+    let input_dir = std::path::absolute(&input_dir).map_err(|e| format!("error: {}: {e}", input_dir.display()))?;
+    let files = || watch::watched_files(&root, &input_dir, year, day);
+    let mut snapshot = Snapshot::take(&files());
+    let mut previous = None;
+
+    println!("Watching {} file(s) of day {day} of {year}, press Ctrl-C to stop", files().len());
+
+    loop {
+        if let Some(answers) = watch_run(&root, &input_dir, year, day, previous.as_ref()) {
+            previous = Some(answers);
+        }
+
+        loop {
+            thread::sleep(interval);
+
+            let changes = Snapshot::take(&files()).changes(&snapshot);
+
+            if changes.is_empty() {
+                continue;
+            }
+
+            // Editors often save a file in several steps, so wait for them to finish before running anything.
+            thread::sleep(interval);
+            snapshot = Snapshot::take(&files());
+
+            println!();
+            for path in changes {
+                println!("Changed {}", path.display());
+            }
+
+            break;
+        }
+    }
+}
+
+fn viz(year: u16, day: u8, part: u8, source: InputSource, delay: Duration, export: ExportArgs) -> Result<(), String> {
+    let visualization = puzzles(year)?.visualization(day)
+        .ok_or_else(|| format!("error: Day {day} has no visualization"))?;
+    let input = source.read(day).map_err(|e| format!("error: {e}"))?;
+
+    let frames = visualization.frames(part, &input).map_err(|e| match e {
+        Error::Parse(error) => error.diagnostic(&source.name(day), &input),
+        error => format!("error: {}: {error}", part_label(part)),
+    })?;
+
+    let Some(output) = export.output else {
+        return player::play(&frames, delay).map_err(|e| format!("error: {e}"));
+    };
+
+    let format = export::Format::from_path(&output).map_err(|e| format!("error: {e}"))?;
+    let options = export::Options {
+        cell_size: export.cell_size as usize,
+        palette: Palette::parse(&export.palette).map_err(|e| format!("error: {e}"))?,
+        delay,
+        frame: export.frame.map(|n| n as usize - 1),
+    };
+
+    let bytes = export::export(&frames, format, &options).map_err(|e| format!("error: {e}"))?;
+    fs::write(&output, bytes).map_err(|e| format!("error: {}: {e}", output.display()))?;
+    println!("Wrote {}", output.display());
+
+    return Ok(());
+}
+
+fn new(year: u16, day: u8, root: PathBuf, input_dir: PathBuf) -> Result<(), String> {
+    for file in scaffold::scaffold(&root, &input_dir, year, day).map_err(|e| format!("error: {e}"))? {
+        println!("Wrote {}", file.display());
+    }
+
+    return Ok(());
+}
+
+fn serve(address: &str, limits: Limits) -> Result<(), String> {
+    let listener = TcpListener::bind(address).map_err(|e| format!("error: Unable to listen on {address}: {e}"))?;
+    let address = listener.local_addr().map_err(|e| format!("error: {e}"))?;
+
+    println!("Listening on http://{address}, press Ctrl-C to stop");
+
+    return Server::new(advent_of_rust::years(), limits).serve(listener).map_err(|e| format!("error: {e}"));
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let year = cli.year.unwrap_or(advent_of_rust::latest().year);
+
+    let result = match cli.command {
+        Command::Run { day, part, input, format, jobs, stream, params, explain, history, no_history } => {
+            let history = (!no_history).then(|| history_file(history, year));
+
+            if stream {
+                run_streamed(year, day, part, input.source(year), history.as_deref())
+            } else if !params.is_empty() {
+                run_with(year, day, part, input.source(year), &params, RunArgs { format, jobs, explain, history })
+            } else {
+                run(year, day, part, input.source(year), RunArgs { format, jobs, explain, history })
+            }
+        }
+        Command::History { day, part, history, limit } => show_history(day, part, &history_file(history, year), limit),
+        Command::Verify { day, part, input, answers, record } => {
+            verify(year, day, part, input.source(year), file_of_year(answers, "answers", year), record)
+        }
+        Command::Check { day, file } => check(year, day, InputSource::from_argument(&file)),
+        Command::Bench { day, part, input, warmup, iterations, baseline, save_baseline, threshold } => {
+            let args = BenchArgs { warmup, iterations, baseline, save_baseline, threshold };
+            benchmark(year, day, part, input.source(year), args)
+        }
+        Command::Fetch { day, input_dir, session } => fetch(year, day, input_dir, session),
+        Command::Submit { day, part, input, submissions, session } => {
+            submit(year, day, part, input.source(year), file_of_year(submissions, "submissions", year), session)
+        }
+        Command::Generate { day, seed, size } => generate(year, day, seed, size),
+        Command::Watch { day, root, input_dir, interval } => {
+            watch(year, day, root, input_dir, Duration::from_millis(interval))
+        }
+        Command::Viz { day, part, input, delay, export } => {
+            viz(year, day, part, input.source(year), Duration::from_millis(delay), export)
+        }
+        Command::New { day, root, input_dir } => new(year, day, root, input_dir),
+        Command::Serve { address, max_body, timeout, request_timeout, jobs, max_connections } => {
+            let limits = Limits {
+                max_body,
+                timeout: Duration::from_secs(timeout),
+                request_timeout: Duration::from_secs(request_timeout),
+                max_running: jobs.map(usize::from)
+                    .unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get())),
+                max_connections: usize::from(max_connections),
+            };
+            serve(&address, limits)
+        }
+    };
+
+    if let Err(message) = result {
+        eprintln!("{message}");
+        return ExitCode::FAILURE;
+    }
+
+    return ExitCode::SUCCESS;
+}
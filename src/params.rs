@@ -0,0 +1,180 @@
+use crate::solution::{self, Answer, Error, Puzzle, Run};
+
+/// Numbers a puzzle statement fixes, like the number of rounds to simulate, which can be changed from the command
+/// line to explore variants of a puzzle.
+pub trait Params: Default {
+    /// Sets the parameter called `name` to the value given as text.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String>;
+
+    /// Names and values of the parameters, in the order they are declared in.
+    fn values(&self) -> Vec<(&'static str, String)>;
+}
+
+/// The default parameters with the `name=value` assignments applied to them in order.
+pub fn from_assignments<P: Params>(assignments: &[(String, String)]) -> Result<P, String> {
+    let mut params = P::default();
+
+    for (name, value) in assignments {
+        params.set(name, value)?;
+    }
+
+    return Ok(params);
+}
+
+/// Splits a `name=value` command line argument into name and value.
+pub fn assignment(argument: &str) -> Result<(String, String), String> {
+    return match argument.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((String::from(name), String::from(value))),
+        _ => Err(format!("expected NAME=VALUE, got \"{argument}\"")),
+    };
+}
+
+/// Declares the parameters of a puzzle as a struct with public fields, implementing [Default] with the given defaults
+/// and [Params] by parsing values with [std::str::FromStr]. A range after a default restricts the values accepted.
+///
+/// ```ignore
+/// params! {
+///     pub struct Params {
+///         /// Number of rounds to simulate
+///         rounds: u64 = 20 in 1..,
+///     }
+/// }
+/// ```
+macro_rules! params {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            $($(#[$field_meta:meta])* $field:ident: $type:ty = $default:literal $(in $range:expr)?,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct $name {
+            $($(#[$field_meta])* pub $field: $type,)*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                return $name { $($field: $default,)* };
+            }
+        }
+
+        impl $crate::params::Params for $name {
+            fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+                match name {
+                    $(stringify!($field) => {
+                        let parsed: $type = value.parse()
+                            .map_err(|e| format!("Invalid value {value:?} of {name}: {e}"))?;
+
+                        $(if !($range).contains(&parsed) {
+                            return Err(format!("Invalid value {value:?} of {name}, expected {}", stringify!($range)));
+                        })?
+
+                        self.$field = parsed;
+                    })*
+                    _ => {
+                        let names: Vec<&str> = vec![$(stringify!($field)),*];
+                        return Err(format!("Unknown parameter {name}, expected one of {}", names.join(", ")));
+                    }
+                }
+
+                return Ok(());
+            }
+
+            fn values(&self) -> Vec<(&'static str, String)> {
+                return vec![$((stringify!($field), self.$field.to_string())),*];
+            }
+        }
+    };
+}
+
+pub(crate) use params;
+
+/// A puzzle whose parts depend on [Params], solved with the default parameters as a [Puzzle].
+pub trait Configure: Puzzle {
+    type Params: Params;
+
+    fn part_one_with(&self, input: &Self::Input<'_>, params: &Self::Params) -> Result<Answer, Error>;
+
+    fn part_two_with(&self, input: &Self::Input<'_>, params: &Self::Params) -> Result<Answer, Error>;
+}
+
+/// Object safe counterpart of [Configure], implemented for every configurable puzzle.
+pub trait Configurable: Sync {
+    fn day(&self) -> u8;
+
+    /// Names and values of the parameters after applying the `name=value` assignments to the defaults.
+    fn params(&self, assignments: &[(String, String)]) -> Result<Vec<(&'static str, String)>, String>;
+
+    /// Same as [crate::solution::Solution::run], with the assignments applied to the default parameters.
+    ///
+    /// Panics if an assignment is invalid, which [Configurable::params] reports.
+    fn run_with(&self, part: u8, input: &str, assignments: &[(String, String)]) -> Result<Run, Error>;
+}
+
+impl<C: Configure> Configurable for C {
+    fn day(&self) -> u8 {
+        return Puzzle::day(self);
+    }
+
+    fn params(&self, assignments: &[(String, String)]) -> Result<Vec<(&'static str, String)>, String> {
+        return Ok(from_assignments::<C::Params>(assignments)?.values());
+    }
+
+    fn run_with(&self, part: u8, input: &str, assignments: &[(String, String)]) -> Result<Run, Error> {
+        let params: C::Params = from_assignments(assignments).unwrap_or_else(|e| panic!("{e}"));
+
+        return solution::run(|| self.parse(input), |parsed| match part {
+            1 => self.part_one_with(parsed, &params),
+            2 => self.part_two_with(parsed, &params),
+            _ => panic!("Invalid part {part}"),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    params! {
+        /// Parameters of a test.
+        pub struct Example {
+            /// Number of rounds
+            rounds: u64 = 20 in 1..,
+            relief: u64 = 3,
+        }
+    }
+
+    #[test]
+    fn defaults() {
+        assert_eq!(Example { rounds: 20, relief: 3 }, Example::default());
+        assert_eq!(vec![("rounds", String::from("20")), ("relief", String::from("3"))], Example::default().values());
+    }
+
+    #[test]
+    fn assignments_are_applied_in_order() {
+        let assignments = [("rounds", "5"), ("relief", "0"), ("rounds", "7")]
+            .map(|(name, value)| (String::from(name), String::from(value)));
+
+        assert_eq!(Ok(Example { rounds: 7, relief: 0 }), from_assignments(&assignments));
+    }
+
+    #[test]
+    fn invalid_assignments() {
+        let error = |name: &str, value: &str| {
+            from_assignments::<Example>(&[(String::from(name), String::from(value))]).unwrap_err()
+        };
+
+        assert_eq!("Unknown parameter round, expected one of rounds, relief", error("round", "5"));
+        assert_eq!("Invalid value \"0\" of rounds, expected 1..", error("rounds", "0"));
+        assert_eq!("Invalid value \"x\" of rounds: invalid digit found in string", error("rounds", "x"));
+    }
+
+    #[test]
+    fn assignment_argument() {
+        assert_eq!(Ok((String::from("rounds"), String::from("500"))), assignment("rounds=500"));
+        assert_eq!(Ok((String::from("rounds"), String::from("=5"))), assignment("rounds==5"));
+        assert!(assignment("rounds").is_err());
+        assert!(assignment("=5").is_err());
+    }
+}
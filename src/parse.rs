@@ -0,0 +1,249 @@
+use std::error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::iter::Enumerate;
+use std::str::FromStr;
+
+/// Error of a puzzle input parser, pointing to the offending position of the input.
+///
+/// Lines and columns are 1-based, columns are counted in characters.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: &str) -> ParseError {
+        return ParseError { line, column, expected: String::from(expected) };
+    }
+
+    /// Renders the error like a compiler diagnostic, quoting the offending line of `input`.
+    pub fn diagnostic(&self, source: &str, input: &str) -> String {
+        let text = input.lines().nth(self.line - 1).unwrap_or("");
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        return format!(
+            "error: expected {}\n{gutter}--> {source}:{}:{}\n{gutter} |\n{} | {text}\n{gutter} | {}^",
+            self.expected,
+            self.line,
+            self.column,
+            self.line,
+            " ".repeat(self.column - 1),
+        );
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        return write!(f, "expected {} at line {}, column {}", self.expected, self.line, self.column);
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Every problem of an input that is parsed line by line with `line`, instead of only the first one.
+pub fn line_problems<'a, T>(input: &'a str, line: impl Fn(Scanner<'a>) -> Result<T, ParseError>) -> Vec<ParseError> {
+    return Lines::new(input).filter_map(|scanner| line(scanner).err()).collect();
+}
+
+/// Iterator over the lines of an input, wrapping every line into a [Scanner].
+pub struct Lines<'a> {
+    lines: Enumerate<std::str::Lines<'a>>,
+    end: (usize, usize),
+}
+
+impl<'a> Lines<'a> {
+    pub fn new(input: &'a str) -> Lines<'a> {
+        return Lines { lines: input.lines().enumerate(), end: (1, 1) };
+    }
+
+    /// Returns the next line or an error pointing to the end of the input if there is none.
+    pub fn expect(&mut self, expected: &str) -> Result<Scanner<'a>, ParseError> {
+        let (line, column) = self.end;
+
+        return self.next().ok_or_else(|| ParseError::new(line, column, expected));
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Scanner<'a>;
+
+    fn next(&mut self) -> Option<Scanner<'a>> {
+        let (index, text) = self.lines.next()?;
+        self.end = (index + 1, text.chars().count() + 1);
+
+        return Some(Scanner::new(index + 1, text));
+    }
+}
+
+/// Consumes a single line of input token by token, keeping track of the current column.
+pub struct Scanner<'a> {
+    line: usize,
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(line: usize, text: &'a str) -> Scanner<'a> {
+        return Scanner { line, text, position: 0 };
+    }
+
+    pub fn line(&self) -> usize {
+        return self.line;
+    }
+
+    pub fn column(&self) -> usize {
+        return self.text[..self.position].chars().count() + 1;
+    }
+
+    pub fn text(&self) -> &'a str {
+        return self.text;
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        return self.position == self.text.len();
+    }
+
+    /// Creates an error at the current column.
+    pub fn error(&self, expected: &str) -> ParseError {
+        return ParseError::new(self.line, self.column(), expected);
+    }
+
+    /// Consumes `literal` if the remaining text starts with it.
+    pub fn accept(&mut self, literal: &str) -> bool {
+        if !self.remaining().starts_with(literal) {
+            return false;
+        }
+
+        self.position += literal.len();
+
+        return true;
+    }
+
+    pub fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        if !self.accept(literal) {
+            return Err(self.error(&format!("`{literal}`")));
+        }
+
+        return Ok(());
+    }
+
+    /// Consumes the first of `options` the remaining text starts with.
+    pub fn one_of<'o>(&mut self, options: &[&'o str], expected: &str) -> Result<&'o str, ParseError> {
+        return options.iter()
+            .find(|option| self.accept(option))
+            .copied()
+            .ok_or_else(|| self.error(expected));
+    }
+
+    /// Consumes an optionally signed integer.
+    pub fn number<T: FromStr>(&mut self, expected: &str) -> Result<T, ParseError> {
+        let start = self.position;
+        let column = self.column();
+
+        self.accept("-");
+        self.take_while(|c| c.is_ascii_digit());
+
+        return match self.text[start..self.position].parse() {
+            Ok(number) => Ok(number),
+            Err(_) => {
+                self.position = start;
+                Err(ParseError::new(self.line, column, expected))
+            }
+        };
+    }
+
+    pub fn char(&mut self, expected: &str) -> Result<char, ParseError> {
+        let c = self.remaining().chars().next().ok_or_else(|| self.error(expected))?;
+        self.position += c.len_utf8();
+
+        return Ok(c);
+    }
+
+    pub fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let remaining = self.remaining();
+        let length = remaining.find(|c| !predicate(c)).unwrap_or(remaining.len());
+        self.position += length;
+
+        return &remaining[..length];
+    }
+
+    /// Consumes the rest of the line, which must not be empty.
+    pub fn rest(&mut self, expected: &str) -> Result<&'a str, ParseError> {
+        if self.is_at_end() {
+            return Err(self.error(expected));
+        }
+
+        let rest = self.remaining();
+        self.position = self.text.len();
+
+        return Ok(rest);
+    }
+
+    pub fn end(&self) -> Result<(), ParseError> {
+        if !self.is_at_end() {
+            return Err(self.error("end of line"));
+        }
+
+        return Ok(());
+    }
+
+    fn remaining(&self) -> &'a str {
+        return &self.text[self.position..];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scanner_consumes_tokens() {
+        let mut scanner = Scanner::new(1, "move 12 from -3 to x");
+
+        assert_eq!(Ok(()), scanner.literal("move "));
+        assert_eq!(Ok(12), scanner.number::<u32>("a number"));
+        assert_eq!(Ok(" from "), scanner.one_of(&[" to ", " from "], "a preposition"));
+        assert_eq!(Ok(-3), scanner.number::<i32>("a number"));
+        assert_eq!(Ok(()), scanner.literal(" to "));
+        assert_eq!(Ok('x'), scanner.char("a character"));
+        assert_eq!(Ok(()), scanner.end());
+    }
+
+    #[test]
+    fn scanner_reports_column_of_unexpected_token() {
+        let mut scanner = Scanner::new(3, "move x");
+
+        assert_eq!(Ok(()), scanner.literal("move "));
+        assert_eq!(Err(ParseError::new(3, 6, "a number")), scanner.number::<u32>("a number"));
+        assert_eq!(Err(ParseError::new(3, 6, "`from`")), scanner.literal("from"));
+        assert_eq!(Err(ParseError::new(3, 6, "end of line")), scanner.end());
+    }
+
+    #[test]
+    fn lines_report_end_of_input() {
+        let mut lines = Lines::new("first\nsecond");
+
+        assert_eq!("first", lines.expect("a line").unwrap().text());
+        assert_eq!("second", lines.expect("a line").unwrap().text());
+        assert_eq!(Err(ParseError::new(2, 7, "a third line")), lines.expect("a third line").map(|s| s.text()));
+    }
+
+    #[test]
+    fn diagnostic() {
+        let error = ParseError::new(2, 6, "a number");
+
+        assert_eq!(
+            vec![
+                "error: expected a number",
+                " --> input/day05.txt:2:6",
+                "  |",
+                "2 | move x from 1 to 2",
+                "  |      ^",
+            ].join("\n"),
+            error.diagnostic("input/day05.txt", "\nmove x from 1 to 2\n")
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use std::io;
+use std::io::{IsTerminal, Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::sync::mpsc::RecvTimeoutError;
+use std::thread;
+use std::time::Duration;
+
+use crate::visualize::Frame;
+
+const MIN_DELAY: Duration = Duration::from_millis(1);
+const MAX_DELAY: Duration = Duration::from_secs(5);
+
+const HELP: &str = "[space] play/pause  [n] step  [b] back  [+/-] speed  [g/G] first/last  [q] quit";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Control {
+    TogglePause,
+    Step,
+    Back,
+    Faster,
+    Slower,
+    First,
+    Last,
+    Quit,
+}
+
+impl Control {
+    pub fn from_key(key: u8) -> Option<Control> {
+        return match key {
+            b' ' | b'p' => Some(Control::TogglePause),
+            b'n' | b'l' => Some(Control::Step),
+            b'b' | b'h' => Some(Control::Back),
+            b'+' | b'=' => Some(Control::Faster),
+            b'-' => Some(Control::Slower),
+            b'g' => Some(Control::First),
+            b'G' => Some(Control::Last),
+            b'q' | 3 => Some(Control::Quit),
+            _ => None,
+        };
+    }
+}
+
+/// Position and speed of the playback of a number of frames.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Player {
+    pub frame: usize,
+    pub frames: usize,
+    pub playing: bool,
+    pub delay: Duration,
+}
+
+impl Player {
+    pub fn new(frames: usize, delay: Duration) -> Player {
+        return Player { frame: 0, frames, playing: frames > 1, delay };
+    }
+
+    /// Applies a control, returning `false` if playback should stop.
+    ///
+    /// Stepping pauses the playback, so the frame stepped to stays on screen.
+    pub fn control(&mut self, control: Control) -> bool {
+        let last = self.frames.saturating_sub(1);
+
+        match control {
+            Control::TogglePause => self.playing = !self.playing && self.frame < last,
+            Control::Step => {
+                self.playing = false;
+                self.frame = (self.frame + 1).min(last);
+            }
+            Control::Back => {
+                self.playing = false;
+                self.frame = self.frame.saturating_sub(1);
+            }
+            Control::Faster => self.delay = (self.delay / 2).max(MIN_DELAY),
+            Control::Slower => self.delay = (self.delay * 2).min(MAX_DELAY),
+            Control::First => self.frame = 0,
+            Control::Last => {
+                self.playing = false;
+                self.frame = last;
+            }
+            Control::Quit => return false,
+        }
+
+        return true;
+    }
+
+    /// Advances to the next frame while playing, pausing on the last one.
+    pub fn tick(&mut self) {
+        if !self.playing {
+            return;
+        }
+
+        self.frame += 1;
+
+        if self.frame + 1 >= self.frames {
+            self.frame = self.frames.saturating_sub(1);
+            self.playing = false;
+        }
+    }
+
+    pub fn status(&self) -> String {
+        return format!(
+            "frame {}/{}  {}  {} ms/frame",
+            self.frame + 1,
+            self.frames,
+            if self.playing { "playing" } else { "paused" },
+            self.delay.as_millis()
+        );
+    }
+}
+
+/// All frames one after the other, for output that is not a terminal.
+pub fn render_all(frames: &[Frame]) -> String {
+    return frames.iter()
+        .map(|frame| format!("== {} ==\n\n{}\n", frame.caption, frame.picture))
+        .collect::<Vec<String>>()
+        .join("\n");
+}
+
+/// Plays the frames in the terminal, reading single key presses from stdin to control the playback.
+///
+/// Falls back to printing all frames if stdin or stdout is not a terminal.
+pub fn play(frames: &[Frame], delay: Duration) -> io::Result<()> {
+    if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        print!("{}", render_all(frames));
+        return Ok(());
+    }
+
+    let terminal = RawTerminal::enable()?;
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let mut key = [0];
+
+        while io::stdin().read_exact(&mut key).is_ok() {
+            let Some(control) = Control::from_key(key[0]) else {
+                continue;
+            };
+
+            if sender.send(control).is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut player = Player::new(frames.len(), delay);
+    let mut stdout = io::stdout();
+
+    loop {
+        let frame = &frames[player.frame];
+        // Clear the screen and move the cursor to the top left corner before drawing the frame.
+        write!(stdout, "\x1b[2J\x1b[H{}\n\n{}\n\n{}\n{HELP}\n", frame.caption, frame.picture, player.status())?;
+        stdout.flush()?;
+
+        let timeout = if player.playing { player.delay } else { Duration::from_secs(3600) };
+
+        match receiver.recv_timeout(timeout) {
+            Ok(control) if !player.control(control) => break,
+            Ok(_) => {}
+            Err(RecvTimeoutError::Timeout) => player.tick(),
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    drop(terminal);
+
+    return Ok(());
+}
+
+/// Puts the terminal into a mode where key presses are read one by one without being echoed, restoring the previous
+/// mode when dropped.
+struct RawTerminal {
+    previous: String,
+}
+
+impl RawTerminal {
+    fn enable() -> io::Result<RawTerminal> {
+        let previous = stty(&["-g"])?;
+        stty(&["-icanon", "-echo", "min", "1"])?;
+        print!("\x1b[?25l");
+
+        return Ok(RawTerminal { previous: previous.trim().to_string() });
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        print!("\x1b[?25h");
+        let _ = stty(&[&self.previous]);
+    }
+}
+
+fn stty(args: &[&str]) -> io::Result<String> {
+    let output = Command::new("stty").args(args).stdin(Stdio::inherit()).output()?;
+
+    if !output.status.success() {
+        return Err(io::Error::other(String::from_utf8_lossy(&output.stderr).trim().to_string()));
+    }
+
+    return Ok(String::from_utf8_lossy(&output.stdout).into_owned());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player() -> Player {
+        return Player::new(3, Duration::from_millis(100));
+    }
+
+    #[test]
+    fn plays_until_last_frame() {
+        let mut player = player();
+
+        player.tick();
+        assert_eq!((1, true), (player.frame, player.playing));
+
+        player.tick();
+        assert_eq!((2, false), (player.frame, player.playing));
+
+        player.tick();
+        assert_eq!((2, false), (player.frame, player.playing));
+    }
+
+    #[test]
+    fn stepping_pauses() {
+        let mut player = player();
+
+        assert!(player.control(Control::Step));
+        assert_eq!((1, false), (player.frame, player.playing));
+
+        player.tick();
+        assert_eq!(1, player.frame);
+
+        player.control(Control::Back);
+        player.control(Control::Back);
+        assert_eq!(0, player.frame);
+
+        player.control(Control::Last);
+        player.control(Control::Step);
+        assert_eq!(2, player.frame);
+
+        player.control(Control::TogglePause);
+        assert!(!player.playing, "Playing can not resume on the last frame");
+    }
+
+    #[test]
+    fn speed() {
+        let mut player = player();
+
+        player.control(Control::Faster);
+        assert_eq!(Duration::from_millis(50), player.delay);
+
+        (0..20).for_each(|_| { player.control(Control::Slower); });
+        assert_eq!(MAX_DELAY, player.delay);
+
+        (0..20).for_each(|_| { player.control(Control::Faster); });
+        assert_eq!(MIN_DELAY, player.delay);
+    }
+
+    #[test]
+    fn quit() {
+        assert!(!player().control(Control::from_key(b'q').unwrap()));
+        assert_eq!(None, Control::from_key(b'x'));
+    }
+
+    #[test]
+    fn render_all_frames() {
+        let frames = vec![Frame::new("Start", "..\n.."), Frame::new("R 1", ".H\n..")];
+
+        assert_eq!("== Start ==\n\n..\n..\n\n== R 1 ==\n\n.H\n..\n", render_all(&frames));
+    }
+}
@@ -0,0 +1,165 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+
+use crate::runner::Failure;
+use crate::solution::{Answer, Run};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, ValueEnum)]
+pub enum Format {
+    /// `Part One: …` lines meant for humans
+    Text,
+    /// An array with an object per result
+    Json,
+    /// A header line followed by a line per result
+    Csv,
+}
+
+/// Outcome of a part, as emitted by `aoc run --format json|csv`.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    #[serde(flatten)]
+    pub outcome: Outcome,
+}
+
+/// Answer of a part along with its timings, or why it has none.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+#[serde(untagged)]
+pub enum Outcome {
+    Solved {
+        #[serde(serialize_with = "rows")]
+        answer: Answer,
+        #[serde(rename = "parse_time_ns", serialize_with = "nanoseconds")]
+        parse_time: Duration,
+        #[serde(rename = "solve_time_ns", serialize_with = "nanoseconds")]
+        solve_time: Duration,
+    },
+    Failed {
+        error: String,
+    },
+}
+
+impl Record {
+    pub fn new(day: u8, part: u8, run: Run) -> Record {
+        let outcome = Outcome::Solved { answer: run.answer, parse_time: run.parse_time, solve_time: run.solve_time };
+        return Record { day, part, outcome };
+    }
+
+    pub fn failed(day: u8, part: u8, failure: &Failure) -> Record {
+        return Record { day, part, outcome: Outcome::Failed { error: failure.to_string() } };
+    }
+}
+
+/// Serializes multi-line answers like the CRT image of day 10 as an array of rows.
+fn rows<S: Serializer>(answer: &Answer, serializer: S) -> Result<S::Ok, S::Error> {
+    return match answer {
+        Answer::Text(text) if text.contains('\n') => serializer.collect_seq(text.lines()),
+        _ => answer.serialize(serializer),
+    };
+}
+
+fn nanoseconds<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    return serializer.serialize_u64(duration.as_nanos() as u64);
+}
+
+pub fn json(records: &[Record]) -> String {
+    return serde_json::to_string_pretty(records).unwrap();
+}
+
+/// Renders the records as CSV, with the rows of multi-line answers separated by newlines within a quoted field.
+///
+/// Failed parts only have an error, the answer and timings of solved parts have none.
+pub fn csv(records: &[Record]) -> String {
+    let mut csv = String::from("day,part,answer,parse_time_ns,solve_time_ns,error\n");
+
+    for record in records {
+        let fields = match &record.outcome {
+            Outcome::Solved { answer, parse_time, solve_time } => {
+                let answer = match answer {
+                    Answer::Text(text) => csv_field(text.trim_end_matches('\n')),
+                    answer => answer.to_string(),
+                };
+
+                format!("{answer},{},{},", parse_time.as_nanos(), solve_time.as_nanos())
+            }
+            Outcome::Failed { error } => format!(",,,{}", csv_field(error)),
+        };
+
+        csv.push_str(&format!("{},{},{fields}\n", record.day, record.part));
+    }
+
+    return csv;
+}
+
+fn csv_field(value: &str) -> String {
+    if !value.contains([',', '"', '\n', '\r']) {
+        return String::from(value);
+    }
+
+    return format!("\"{}\"", value.replace('"', "\"\""));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        return vec![
+            Record::new(5, 1, Run {
+                answer: Answer::from(String::from("CMZ")),
+                parse_time: Duration::from_nanos(1200),
+                solve_time: Duration::from_nanos(340),
+            }),
+            Record::new(10, 2, Run {
+                answer: Answer::from(String::from("##..\n.##.\n")),
+                parse_time: Duration::from_nanos(5),
+                solve_time: Duration::from_micros(2),
+            }),
+            Record::new(11, 2, Run {
+                answer: Answer::from(2713310158u64),
+                parse_time: Duration::from_nanos(7),
+                solve_time: Duration::from_millis(3),
+            }),
+            Record::failed(12, 1, &Failure::Panic(String::from("no path, to the top"))),
+        ];
+    }
+
+    #[test]
+    fn json_with_rows_of_multi_line_answers() {
+        let json: serde_json::Value = serde_json::from_str(&json(&records())).unwrap();
+
+        assert_eq!(
+            serde_json::json!([
+                {"day": 5, "part": 1, "answer": "CMZ", "parse_time_ns": 1200, "solve_time_ns": 340},
+                {"day": 10, "part": 2, "answer": ["##..", ".##."], "parse_time_ns": 5, "solve_time_ns": 2000},
+                {"day": 11, "part": 2, "answer": 2713310158u64, "parse_time_ns": 7, "solve_time_ns": 3000000},
+                {"day": 12, "part": 1, "error": "panicked: no path, to the top"},
+            ]),
+            json
+        );
+    }
+
+    #[test]
+    fn csv_with_quoted_multi_line_answers() {
+        assert_eq!(
+            vec![
+                "day,part,answer,parse_time_ns,solve_time_ns,error",
+                "5,1,CMZ,1200,340,",
+                "10,2,\"##..\n.##.\",5,2000,",
+                "11,2,2713310158,7,3000000,",
+                "12,1,,,,\"panicked: no path, to the top\"",
+                "",
+            ].join("\n"),
+            csv(&records())
+        );
+    }
+
+    #[test]
+    fn csv_field_escapes_quotes() {
+        assert_eq!("\"a \"\"b\"\", c\"", csv_field("a \"b\", c"));
+        assert_eq!("abc", csv_field("abc"));
+    }
+}
@@ -0,0 +1,300 @@
+use std::any::Any;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use crate::bench::format_duration;
+use crate::solution::{Answer, Error, Run, Solution};
+
+/// Why a part of a day did not produce an answer.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Failure {
+    /// The input of the day could not be read.
+    Input(String),
+    Error(Error),
+    Panic(String),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        return match self {
+            Failure::Input(message) => write!(f, "{message}"),
+            Failure::Error(error) => write!(f, "{error}"),
+            Failure::Panic(message) => write!(f, "panicked: {message}"),
+        };
+    }
+}
+
+/// Results of the parts of a single day.
+#[derive(Debug)]
+pub struct DayReport {
+    pub day: u8,
+    pub parts: Vec<(u8, Result<Run, Failure>)>,
+}
+
+impl DayReport {
+    pub fn failures(&self) -> usize {
+        return self.parts.iter().filter(|(_, result)| result.is_err()).count();
+    }
+}
+
+/// Message of a panic, as passed to `panic!`.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+
+    return payload.downcast_ref::<String>().cloned().unwrap_or_else(|| String::from("unknown panic"));
+}
+
+/// Runs the parts of a day, turning panics into failures so they only affect the part that panicked.
+pub fn run_day(solution: &dyn Solution, parts: &[u8], input: Result<String, String>) -> DayReport {
+    let day = solution.day();
+
+    let input = match input {
+        Ok(input) => input,
+        Err(message) => {
+            let failed = parts.iter().map(|part| (*part, Err(Failure::Input(message.clone())))).collect();
+
+            return DayReport { day, parts: failed };
+        }
+    };
+
+    let parts = parts.iter()
+        .map(|part| {
+            let result = match panic::catch_unwind(AssertUnwindSafe(|| solution.run(*part, &input))) {
+                Ok(result) => result.map_err(Failure::Error),
+                Err(payload) => Err(Failure::Panic(panic_message(payload.as_ref()))),
+            };
+
+            return (*part, result);
+        })
+        .collect();
+
+    return DayReport { day, parts };
+}
+
+/// Runs the days on up to `threads` threads at once, reading the input of a day with `input`.
+///
+/// The reports are in the order of the solutions, no matter in which order the days finish.
+pub fn run_all(
+    solutions: &[&dyn Solution],
+    parts: &[u8],
+    input: impl Fn(u8) -> Result<String, String> + Sync,
+    threads: usize,
+) -> Vec<DayReport> {
+    let next = AtomicUsize::new(0);
+    let reports: Mutex<Vec<(usize, DayReport)>> = Mutex::new(Vec::new());
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, solutions.len().max(1)) {
+            scope.spawn(|| {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+
+                    let Some(solution) = solutions.get(index) else {
+                        break;
+                    };
+
+                    let report = run_day(*solution, parts, input(solution.day()));
+                    reports.lock().unwrap().push((index, report));
+                }
+            });
+        }
+    });
+
+    let mut reports = reports.into_inner().unwrap();
+    reports.sort_by_key(|(index, _)| *index);
+
+    return reports.into_iter().map(|(_, report)| report).collect();
+}
+
+/// Table of the answers, timings and failures of every part, followed by the totals and the multi-line answers.
+pub fn summary(reports: &[DayReport], elapsed: Duration) -> String {
+    let mut lines = vec![format!("{:<16} {:<20} {:>12} {:>12}  {}", "", "answer", "parse", "solve", "status")];
+    let mut multi_line = Vec::new();
+    let mut solving_time = Duration::ZERO;
+
+    for report in reports {
+        for (part, result) in &report.parts {
+            let label = format!("Day {:02} Part {}", report.day, if *part == 1 { "One" } else { "Two" });
+
+            lines.push(match result {
+                Ok(run) => {
+                    solving_time += run.parse_time + run.solve_time;
+
+                    let answer = match &run.answer {
+                        Answer::Text(text) if text.contains('\n') => {
+                            multi_line.push(format!("{label}:\n{}", text.trim_end()));
+                            String::from("(see below)")
+                        }
+                        answer => answer.to_string(),
+                    };
+
+                    format!(
+                        "{label:<16} {answer:<20} {:>12} {:>12}  ok",
+                        format_duration(run.parse_time),
+                        format_duration(run.solve_time)
+                    )
+                }
+                Err(failure) => format!("{label:<16} {:<20} {:>12} {:>12}  {failure}", "-", "-", "-"),
+            });
+        }
+    }
+
+    let parts: usize = reports.iter().map(|r| r.parts.len()).sum();
+    let failed: usize = reports.iter().map(DayReport::failures).sum();
+
+    lines.push(String::new());
+    lines.push(format!(
+        "{} days, {parts} parts, {failed} failed in {} ({} parsing and solving)",
+        reports.len(),
+        format_duration(elapsed),
+        format_duration(solving_time)
+    ));
+
+    for answer in multi_line {
+        lines.push(String::new());
+        lines.push(answer);
+    }
+
+    return lines.join("\n") + "\n";
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::ParseError;
+    use crate::solution::Puzzle;
+
+    use super::*;
+
+    /// Sums the numbers of the input, panicking on part two if there are none.
+    struct Sum;
+
+    impl Puzzle for Sum {
+        type Input<'a> = Vec<u32>;
+
+        fn day(&self) -> u8 {
+            return 24;
+        }
+
+        fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+            return input.lines()
+                .enumerate()
+                .map(|(index, line)| line.parse().map_err(|_| ParseError::new(index + 1, 1, "a number")))
+                .collect();
+        }
+
+        fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+            return Ok(input.iter().sum::<u32>().into());
+        }
+
+        fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+            if input.is_empty() {
+                panic!("no numbers");
+            }
+
+            return Ok(input.iter().max().unwrap().to_string().into());
+        }
+    }
+
+    struct Broken;
+
+    impl Puzzle for Broken {
+        type Input<'a> = ();
+
+        fn day(&self) -> u8 {
+            return 25;
+        }
+
+        fn parse<'a>(&self, _input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+            return Ok(());
+        }
+
+        fn part_one(&self, _input: &Self::Input<'_>) -> Result<Answer, Error> {
+            panic!("index out of bounds: the len is {} but the index is {}", 0, 3);
+        }
+
+        fn part_two(&self, _input: &Self::Input<'_>) -> Result<Answer, Error> {
+            return Err(Error::Unsolvable(String::from("no answer")));
+        }
+    }
+
+    fn answers(report: &DayReport) -> Vec<Result<Answer, Failure>> {
+        return report.parts.iter().map(|(_, result)| result.clone().map(|run| run.answer)).collect();
+    }
+
+    #[test]
+    fn panics_only_fail_the_part_that_panicked() {
+        let report = run_day(&Sum, &[1, 2], Ok(String::new()));
+
+        assert_eq!(vec![Ok(Answer::Number(0)), Err(Failure::Panic(String::from("no numbers")))], answers(&report));
+        assert_eq!(1, report.failures());
+    }
+
+    #[test]
+    fn run_all_keeps_order_and_isolates_failures() {
+        let solutions: Vec<&dyn Solution> = vec![&Broken, &Sum];
+
+        let reports = run_all(&solutions, &[1, 2], |day| match day {
+            24 => Ok(String::from("1\n5\n3")),
+            _ => Ok(String::new()),
+        }, 4);
+
+        assert_eq!(vec![25, 24], reports.iter().map(|r| r.day).collect::<Vec<_>>());
+        assert_eq!(
+            vec![
+                Err(Failure::Panic(String::from("index out of bounds: the len is 0 but the index is 3"))),
+                Err(Failure::Error(Error::Unsolvable(String::from("no answer")))),
+            ],
+            answers(&reports[0])
+        );
+        assert_eq!(vec![Ok(Answer::Number(9)), Ok(Answer::Text(String::from("5")))], answers(&reports[1]));
+    }
+
+    #[test]
+    fn missing_input_fails_every_part() {
+        let reports = run_all(&[&Sum], &[1, 2], |_| Err(String::from("Input file day24.txt does not exist")), 1);
+
+        assert_eq!(2, reports[0].failures());
+        assert_eq!(Err(Failure::Input(String::from("Input file day24.txt does not exist"))), answers(&reports[0])[1]);
+    }
+
+    #[test]
+    fn summary_table() {
+        let run = |answer: Answer| {
+            return Run { answer, parse_time: Duration::from_micros(3), solve_time: Duration::from_millis(2) };
+        };
+        let reports = vec![
+            DayReport { day: 1, parts: vec![(1, Ok(run(Answer::Number(24000))))] },
+            DayReport { day: 10, parts: vec![(2, Ok(run(Answer::Text(String::from("##..\n..##\n")))))] },
+            DayReport {
+                day: 11,
+                parts: vec![(1, Err(Failure::Panic(String::from("attempt to multiply with overflow"))))],
+            },
+        ];
+
+        assert_eq!(
+            vec![
+                "                 answer                      parse        solve  status",
+                "Day 01 Part One  24000                    3.000 µs     2.000 ms  ok",
+                "Day 10 Part Two  (see below)              3.000 µs     2.000 ms  ok",
+                "Day 11 Part One  -                               -            -  panicked: attempt to multiply with \
+                 overflow",
+                "",
+                "3 days, 3 parts, 1 failed in 5.000 ms (4.006 ms parsing and solving)",
+                "",
+                "Day 10 Part Two:",
+                "##..",
+                "..##",
+                "",
+            ].join("\n"),
+            summary(&reports, Duration::from_millis(5))
+        );
+    }
+}
@@ -0,0 +1,437 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use crate::{fixtures, fuzz};
+
+/// Module of a new day, with `__YEAR__` standing for the year, `__DAY__` for the day and `__NN__` for the zero-padded
+/// day.
+const TEMPLATE: &str = r#"use crate::parse::{Lines, ParseError};
+use crate::solution::{Answer, Error, Puzzle};
+
+fn input_from_string(input: &str) -> Result<Vec<&str>, ParseError> {
+    return Lines::new(input).map(|line| Ok(line.text())).collect();
+}
+
+pub fn part_one(_input: &[&str]) -> u32 {
+    todo!()
+}
+
+pub fn part_two(_input: &[&str]) -> u32 {
+    todo!()
+}
+
+pub struct Day__NN__;
+
+impl Puzzle for Day__NN__ {
+    type Input<'a> = Vec<&'a str>;
+
+    fn day(&self) -> u8 {
+        return __DAY__;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return input_from_string(input);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(part_one(input).into());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(part_two(input).into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_input() -> String {
+        return String::from(include_str!("../../examples/__YEAR__/day__NN__/example.txt"));
+    }
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(0, part_one(&input_from_string(&example_input()).unwrap()));
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(0, part_two(&input_from_string(&example_input()).unwrap()));
+    }
+}
+"#;
+
+/// Module of a new year without any days, with `__YEAR__` standing for the year.
+const YEAR_TEMPLATE: &str = r#"use crate::generate::Generator;
+use crate::solution::Solution;
+use crate::year::Year;
+
+static SOLUTIONS: [&dyn Solution; 0] = [
+];
+
+pub static YEAR: Year = Year {
+    year: __YEAR__,
+    solutions: &SOLUTIONS,
+    pairs: &[],
+    visualizations: &[],
+    streams: &[],
+    configurables: &[],
+    generator,
+};
+
+/// Generator of random but valid inputs of a day, used to compare implementations and to stress test them.
+fn generator(_day: u8) -> Option<Generator> {
+    return None;
+}
+"#;
+
+/// Fuzz target of a new day, see [crate::fuzz::solve].
+const FUZZ_TEMPLATE: &str = r#"#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use advent_of_rust::fuzz;
+use advent_of_rust::y__YEAR__::day__NN__::Day__NN__;
+
+fuzz_target!(|data: &[u8]| fuzz::solve(&Day__NN__, data));
+"#;
+
+/// Expected answers of the example, see [crate::fixtures::Fixture].
+const EXPECTED: &str = "# part_one = 0\n# part_two = 0\n";
+
+/// Source of the module of a new day, with stubs for both parts and the example tests.
+pub fn module(year: u16, day: u8) -> String {
+    return TEMPLATE
+        .replace("__YEAR__", &year.to_string())
+        .replace("__NN__", &format!("{day:02}"))
+        .replace("__DAY__", &day.to_string());
+}
+
+/// Source of the module of a new year, see [crate::year::Year].
+pub fn year_module(year: u16) -> String {
+    return YEAR_TEMPLATE.replace("__YEAR__", &year.to_string());
+}
+
+/// Source of the fuzz target of a new day.
+pub fn fuzz_target(year: u16, day: u8) -> String {
+    return FUZZ_TEMPLATE.replace("__YEAR__", &year.to_string()).replace("__NN__", &format!("{day:02}"));
+}
+
+/// Adds the binary of the fuzz target of a day to the manifest of the fuzz targets.
+pub fn register_fuzz_target(manifest: &str, year: u16, day: u8) -> Result<String, String> {
+    let target = fuzz::target(year, day);
+
+    if manifest.lines().any(|line| line == format!("name = \"{target}\"")) {
+        return Err(format!("The fuzz target {target} is already registered"));
+    }
+
+    return Ok(format!(
+        "{}\n\n[[bin]]\nname = \"{target}\"\npath = \"fuzz_targets/{target}.rs\"\n\
+         test = false\ndoc = false\nbench = false\n",
+        manifest.trim_end(),
+    ));
+}
+
+/// Adds the module of a day to the source of the `mod.rs` of its year and registers its solution with the runner.
+pub fn register(year_module: &str, day: u8) -> Result<String, String> {
+    let module = format!("day{day:02}");
+
+    if year_module.lines().any(|line| line == format!("pub mod {module};")) {
+        return Err(format!("Day {day} is already registered"));
+    }
+
+    let mut lines: Vec<String> = year_module.lines().map(String::from).collect();
+
+    declare(&mut lines, &module, "static SOLUTIONS: [&dyn Solution; ")?;
+    add_entry(&mut lines, "static SOLUTIONS: [&dyn Solution; ", &format!("    &{module}::Day{day:02},"))?;
+
+    return Ok(lines.join("\n") + "\n");
+}
+
+/// Adds the module of a year to the source of `lib.rs` and registers it with the runner.
+pub fn register_year(lib: &str, year: u16) -> Result<String, String> {
+    let module = format!("y{year}");
+
+    if lib.lines().any(|line| line == format!("pub mod {module};")) {
+        return Err(format!("Year {year} is already registered"));
+    }
+
+    let mut lines: Vec<String> = lib.lines().map(String::from).collect();
+
+    declare(&mut lines, &module, "static YEARS: [&Year; ")?;
+    add_entry(&mut lines, "static YEARS: [&Year; ", &format!("    &{module}::YEAR,"))?;
+
+    return Ok(lines.join("\n") + "\n");
+}
+
+/// Inserts the declaration of `module` in order among the declarations of its siblings, which are named like it with
+/// a different number, or in a paragraph of its own before the `array` if it is the first one.
+fn declare(lines: &mut Vec<String>, module: &str, array: &str) -> Result<(), String> {
+    let declaration = format!("pub mod {module};");
+    let is_sibling = |line: &String| {
+        let name = line.strip_prefix("pub mod ").and_then(|line| line.strip_suffix(';'));
+
+        return name.is_some_and(|name| family(name) == family(module));
+    };
+
+    let Some(modules) = lines.iter().position(is_sibling) else {
+        let position = lines.iter().position(|line| line.starts_with(array))
+            .ok_or_else(|| format!("Unable to find the declarations of the {} modules", family(module)))?;
+        lines.splice(position..position, [declaration, String::new()]);
+
+        return Ok(());
+    };
+
+    let before = lines[modules..].iter().take_while(|line| is_sibling(line) && **line < declaration).count();
+    lines.insert(modules + before, declaration);
+
+    return Ok(());
+}
+
+/// Name of a module without its number, e.g. `day` for `day07`.
+fn family(module: &str) -> &str {
+    return module.trim_end_matches(|c: char| c.is_ascii_digit());
+}
+
+/// Inserts `entry` in order into the static `array`, whose declaration starts with the given prefix, and updates its
+/// length.
+fn add_entry(lines: &mut Vec<String>, array: &str, entry: &str) -> Result<(), String> {
+    let start = lines.iter().position(|line| line.starts_with(array))
+        .ok_or_else(|| format!("Unable to find the {} array", array.split([' ', ':']).nth(1).unwrap_or(array)))?;
+    let count = lines[start + 1..].iter().take_while(|line| line.starts_with("    &")).count();
+    let before = lines[start + 1..start + 1 + count].iter().take_while(|line| line.as_str() < entry).count();
+    lines[start] = format!("{array}{}] = [", count + 1);
+    lines.insert(start + 1 + before, String::from(entry));
+
+    return Ok(());
+}
+
+/// Creates the module of a new day in the crate at `root` along with an empty example fixture, registers it and
+/// creates an empty input file in the directory of its year in `input_dir`.
+///
+/// The module of the year is created as well if this is its first day, and so is a fuzz target if the crate has
+/// fuzz targets. Returns the files that were created or changed.
+pub fn scaffold(root: &Path, input_dir: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    let year_dir = root.join("src").join(format!("y{year}"));
+    let module_path = year_dir.join(format!("day{day:02}.rs"));
+    let year_path = year_dir.join("mod.rs");
+    let lib_path = root.join("src").join("lib.rs");
+    let input_dir = input_dir.join(year.to_string());
+    let input_path = input_dir.join(format!("day{day:02}.txt"));
+    let examples = root.join(fixtures::DIRECTORY).join(year.to_string()).join(format!("day{day:02}"));
+    let fuzz_dir = root.join(fuzz::DIRECTORY);
+    let manifest_path = fuzz_dir.join("Cargo.toml");
+
+    if module_path.exists() {
+        return Err(format!("{} already exists", module_path.display()));
+    }
+
+    let mut files = Vec::new();
+
+    let year_module = match fs::read_to_string(&year_path) {
+        Ok(year_module) => year_module,
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            let lib = fs::read_to_string(&lib_path).map_err(|e| format!("Unable to read {}: {e}", lib_path.display()))?;
+            let lib = register_year(&lib, year)?;
+
+            fs::create_dir_all(&year_dir).map_err(|e| format!("Unable to create {}: {e}", year_dir.display()))?;
+            files.push(write(lib_path, &lib)?);
+
+            year_module(year)
+        }
+        Err(e) => return Err(format!("Unable to read {}: {e}", year_path.display())),
+    };
+    let year_module = register(&year_module, day)?;
+
+    if !examples.exists() {
+        fs::create_dir_all(&examples).map_err(|e| format!("Unable to create {}: {e}", examples.display()))?;
+        files.push(write(examples.join("example.txt"), "")?);
+        files.push(write(examples.join("example.expected"), EXPECTED)?);
+    }
+
+    files.push(write(module_path, &module(year, day))?);
+    files.push(write(year_path, &year_module)?);
+
+    if manifest_path.exists() {
+        let manifest = fs::read_to_string(&manifest_path)
+            .map_err(|e| format!("Unable to read {}: {e}", manifest_path.display()))?;
+        let target_path = fuzz_dir.join("fuzz_targets").join(format!("{}.rs", fuzz::target(year, day)));
+
+        files.push(write(manifest_path, &register_fuzz_target(&manifest, year, day)?)?);
+        files.push(write(target_path, &fuzz_target(year, day))?);
+    }
+
+    if !input_path.exists() {
+        fs::create_dir_all(&input_dir)
+            .and_then(|_| fs::write(&input_path, ""))
+            .map_err(|e| format!("Unable to write {}: {e}", input_path.display()))?;
+        files.push(input_path);
+    }
+
+    return Ok(files);
+}
+
+fn write(path: PathBuf, content: &str) -> Result<PathBuf, String> {
+    fs::write(&path, content).map_err(|e| format!("Unable to write {}: {e}", path.display()))?;
+
+    return Ok(path);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2022;
+
+    use super::*;
+
+    fn lib() -> String {
+        return vec![
+            "pub mod solution;",
+            "",
+            "pub mod day01;",
+            "pub mod day03;",
+            "",
+            "static SOLUTIONS: [&dyn Solution; 2] = [",
+            "    &day01::Day01,",
+            "    &day03::Day03,",
+            "];",
+            "",
+        ].join("\n");
+    }
+
+    #[test]
+    fn module_of_day() {
+        let module = module(2022, 7);
+
+        assert!(module.contains("pub struct Day07;"));
+        assert!(module.contains("impl Puzzle for Day07 {"));
+        assert!(module.contains("        return 7;"));
+        assert!(module.contains("include_str!(\"../../examples/2022/day07/example.txt\")"));
+        assert!(!module.contains("__"));
+    }
+
+    #[test]
+    fn fuzz_target_of_day() {
+        let target = fuzz_target(2023, 7);
+
+        assert!(target.contains("use advent_of_rust::y2023::day07::Day07;"));
+        assert!(target.contains("fuzz::solve(&Day07, data)"));
+        assert!(!target.contains("__"));
+    }
+
+    #[test]
+    fn register_fuzz_target_of_day() {
+        let manifest = "[package]\nname = \"advent-of-rust-fuzz\"\n\n[[bin]]\nname = \"y2022_day01\"\n";
+        let result = register_fuzz_target(manifest, 2022, 2).unwrap();
+
+        assert!(result.ends_with(
+            "name = \"y2022_day01\"\n\n[[bin]]\nname = \"y2022_day02\"\npath = \"fuzz_targets/y2022_day02.rs\"\n\
+             test = false\ndoc = false\nbench = false\n"
+        ));
+        assert_eq!(
+            Err(String::from("The fuzz target y2022_day01 is already registered")),
+            register_fuzz_target(manifest, 2022, 1)
+        );
+    }
+
+    #[test]
+    fn register_day() {
+        assert_eq!(
+            vec![
+                "pub mod solution;",
+                "",
+                "pub mod day01;",
+                "pub mod day02;",
+                "pub mod day03;",
+                "",
+                "static SOLUTIONS: [&dyn Solution; 3] = [",
+                "    &day01::Day01,",
+                "    &day02::Day02,",
+                "    &day03::Day03,",
+                "];",
+                "",
+            ].join("\n"),
+            register(&lib(), 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn register_last_day() {
+        let result = register(&lib(), 12).unwrap();
+
+        assert!(result.contains("pub mod day03;\npub mod day12;\n\nstatic SOLUTIONS: [&dyn Solution; 3] = ["));
+        assert!(result.contains("    &day03::Day03,\n    &day12::Day12,\n];"));
+    }
+
+    /// Modules of the days in the static `array` of the source of a `mod.rs`, e.g. `day07` for `    &day07::Day07,`.
+    fn entries(year_module: &str, array: &str) -> Vec<String> {
+        return year_module.lines()
+            .skip_while(|line| !line.starts_with(array))
+            .skip(1)
+            .take_while(|line| *line != "];")
+            .map(|line| line.split_once('&').and_then(|(_, entry)| entry.split_once("::")).unwrap().0.to_owned())
+            .collect();
+    }
+
+    #[test]
+    fn register_keeps_registry_invariants() {
+        let day = (1..=25).find(|day| y2022::YEAR.solution(*day).is_none()).unwrap();
+        let result = register(include_str!("y2022/mod.rs"), day).unwrap();
+        let solutions = entries(&result, "static SOLUTIONS: ");
+        let declared = result.lines().filter_map(|line| line.strip_prefix("pub mod day")).count();
+
+        assert!(result.contains(&format!("static SOLUTIONS: [&dyn Solution; {}] = [", solutions.len())));
+        assert_eq!(declared, solutions.len());
+        assert!(solutions.contains(&format!("day{day:02}")));
+        assert!(entries(&result, "static PAIRS: ").iter().all(|day| solutions.contains(day)));
+    }
+
+    #[test]
+    fn register_existing_day() {
+        assert_eq!(Err(String::from("Day 3 is already registered")), register(&lib(), 3));
+    }
+
+    #[test]
+    fn register_first_day_of_year() {
+        let result = register(&year_module(2023), 1).unwrap();
+
+        assert!(result.contains(
+            "use crate::year::Year;\n\npub mod day01;\n\nstatic SOLUTIONS: [&dyn Solution; 1] = [\n"
+        ));
+        assert!(result.contains("    &day01::Day01,\n];\n\npub static YEAR: Year = Year {\n    year: 2023,"));
+    }
+
+    #[test]
+    fn register_year() {
+        let lib = vec![
+            "pub mod solution;",
+            "pub mod year;",
+            "",
+            "pub mod y2022;",
+            "",
+            "static YEARS: [&Year; 1] = [",
+            "    &y2022::YEAR,",
+            "];",
+            "",
+        ].join("\n");
+
+        assert_eq!(
+            vec![
+                "pub mod solution;",
+                "pub mod year;",
+                "",
+                "pub mod y2022;",
+                "pub mod y2023;",
+                "",
+                "static YEARS: [&Year; 2] = [",
+                "    &y2022::YEAR,",
+                "    &y2023::YEAR,",
+                "];",
+                "",
+            ].join("\n"),
+            super::register_year(&lib, 2023).unwrap()
+        );
+        assert_eq!(Err(String::from("Year 2022 is already registered")), super::register_year(&lib, 2022));
+    }
+}
@@ -0,0 +1,573 @@
+use std::io;
+use std::io::{BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::bench::format_duration;
+use crate::report::Record;
+use crate::runner;
+use crate::runner::Failure;
+use crate::solution::{Error, Run};
+use crate::year::Year;
+
+/// Largest request line and headers accepted, in bytes.
+const MAX_HEAD: u64 = 8 * 1024;
+
+/// Limits protecting the server from large inputs and from inputs that take too long to solve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Largest request body accepted, in bytes.
+    pub max_body: usize,
+    /// Time a part may take before the request fails.
+    pub timeout: Duration,
+    /// Time a client has to send its whole request, and then to receive the whole response.
+    pub request_timeout: Duration,
+    /// Number of parts solved at once, counting those that timed out but are still running.
+    pub max_running: usize,
+    /// Number of connections answered at once, each on a thread of its own.
+    pub max_connections: usize,
+}
+
+/// A request as far as the server cares about it.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: Vec<u8>,
+}
+
+/// Request line and headers of a request.
+struct Head {
+    method: String,
+    path: String,
+    headers: Vec<(String, String)>,
+}
+
+impl Head {
+    fn header(&self, name: &str) -> Option<&str> {
+        return self.headers.iter().find(|(key, _)| key.eq_ignore_ascii_case(name)).map(|(_, value)| value.as_str());
+    }
+}
+
+/// Status and JSON body of a response.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Response {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl Response {
+    fn error(status: u16, message: &str) -> Response {
+        return Response { status, body: json!({"error": message}) };
+    }
+
+    fn busy(message: &str) -> Response {
+        return Response { status: 503, body: json!({"error": message, "kind": "busy"}) };
+    }
+
+    /// A part that did not produce an answer, with the position of the offending input for parse errors.
+    fn failure(failure: &Failure) -> Response {
+        return match failure {
+            Failure::Error(Error::Parse(error)) => Response {
+                status: 422,
+                body: json!({
+                    "error": failure.to_string(),
+                    "kind": "parse",
+                    "line": error.line,
+                    "column": error.column,
+                    "expected": error.expected,
+                }),
+            },
+            Failure::Error(error) => {
+                Response { status: 422, body: json!({"error": error.to_string(), "kind": "unsolvable"}) }
+            }
+            Failure::Panic(message) => Response { status: 500, body: json!({"error": message, "kind": "panic"}) },
+            Failure::Input(message) => Response::error(400, message),
+        };
+    }
+}
+
+/// Answer of a part as returned by the server, the same fields as `aoc run --format json` plus the year.
+#[derive(Serialize)]
+struct Solved {
+    year: u16,
+    #[serde(flatten)]
+    record: Record,
+}
+
+/// HTTP server solving the parts of every day of `years`.
+///
+/// `GET /days` lists the days by year, `POST /<year>/day/<day>/part/<part>` solves a part of the input in the body.
+pub struct Server {
+    years: &'static [&'static Year],
+    limits: Limits,
+    running: Arc<AtomicUsize>,
+    connections: AtomicUsize,
+}
+
+impl Server {
+    pub fn new(years: &'static [&'static Year], limits: Limits) -> Server {
+        return Server { years, limits, running: Arc::new(AtomicUsize::new(0)), connections: AtomicUsize::new(0) };
+    }
+
+    /// Answers the connections of `listener` on a thread each, logging every request. Connections beyond
+    /// [Limits::max_connections] are answered as busy right away. Only returns if accepting a connection fails.
+    pub fn serve(self, listener: TcpListener) -> Result<(), String> {
+        let server = Arc::new(self);
+
+        for stream in listener.incoming() {
+            let stream = stream.map_err(|e| format!("Unable to accept a connection: {e}"))?;
+
+            if server.connections.fetch_add(1, Ordering::SeqCst) >= server.limits.max_connections {
+                server.connections.fetch_sub(1, Ordering::SeqCst);
+
+                if let Err(e) = server.reject(&stream) {
+                    eprintln!("error: {e}");
+                }
+
+                continue;
+            }
+
+            let server = Arc::clone(&server);
+
+            thread::spawn(move || {
+                if let Err(e) = server.handle(&stream) {
+                    eprintln!("error: {e}");
+                }
+
+                server.connections.fetch_sub(1, Ordering::SeqCst);
+            });
+        }
+
+        return Ok(());
+    }
+
+    /// Reads a request from `stream` and writes the response, closing the connection afterwards.
+    pub fn handle(&self, stream: &TcpStream) -> io::Result<()> {
+        let start = Instant::now();
+        let deadline = start + self.limits.request_timeout;
+        let mut reader = BufReader::new(Deadline { stream, deadline });
+
+        let (line, response) = match self.read_request(&mut reader, &mut Deadline { stream, deadline }) {
+            Ok(request) => (format!("{} {}", request.method, request.path), self.respond(&request)),
+            Err(response) => (String::from("-"), response),
+        };
+
+        println!("{line} {} {}", response.status, format_duration(start.elapsed()));
+
+        let deadline = Instant::now() + self.limits.request_timeout;
+        return write_response(&mut Deadline { stream, deadline }, &response);
+    }
+
+    /// Answers a connection as busy without reading its request.
+    fn reject(&self, stream: &TcpStream) -> io::Result<()> {
+        let start = Instant::now();
+        let mut writer = Deadline { stream, deadline: start + self.limits.request_timeout };
+        let result = write_response(&mut writer, &Response::busy("Too many connections are open"));
+        println!("- 503 {}", format_duration(start.elapsed()));
+
+        return result;
+    }
+
+    /// Reads the head of a request and, unless the body is too large, the body.
+    fn read_request(&self, reader: &mut impl BufRead, writer: &mut impl Write) -> Result<Request, Response> {
+        let head = read_head(reader)?;
+
+        if head.header("Transfer-Encoding").is_some() {
+            return Err(Response::error(411, "The length of the body has to be given as Content-Length"));
+        }
+
+        let length = match head.header("Content-Length") {
+            None => 0,
+            Some(length) => length.parse::<usize>().map_err(|_| Response::error(400, "Invalid Content-Length"))?,
+        };
+
+        if length > self.limits.max_body {
+            return Err(Response::error(413, &format!("The body is larger than {} bytes", self.limits.max_body)));
+        }
+
+        if head.header("Expect").is_some_and(|expect| expect.eq_ignore_ascii_case("100-continue")) {
+            writer.write_all(b"HTTP/1.1 100 Continue\r\n\r\n")
+                .map_err(|e| Response::error(400, &format!("Unable to continue: {e}")))?;
+        }
+
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).map_err(|e| read_error(e, "the body"))?;
+
+        return Ok(Request { method: head.method, path: head.path, body });
+    }
+
+    /// Routes a request to the list of days or to the part it asks to solve.
+    pub fn respond(&self, request: &Request) -> Response {
+        let path = request.path.split('?').next().unwrap_or_default();
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+        return match (request.method.as_str(), &segments[..]) {
+            ("GET", ["days"]) => self.days(),
+            ("POST", [year, "day", day, "part", part]) => match (year.parse(), day.parse(), part.parse()) {
+                (Ok(year), Ok(day), Ok(part @ (1 | 2))) => self.solve(year, day, part, &request.body),
+                _ => Response::error(404, &format!("No such part: {path}")),
+            },
+            (_, ["days"]) | (_, [_, "day", _, "part", _]) => {
+                Response::error(405, &format!("{} is not allowed for {path}", request.method))
+            }
+            _ => Response::error(404, &format!("No such endpoint: {path}")),
+        };
+    }
+
+    fn days(&self) -> Response {
+        let years: Vec<Value> = self.years.iter()
+            .map(|year| json!({"year": year.year, "days": year.solutions.iter().map(|s| s.day()).collect::<Vec<u8>>()}))
+            .collect();
+
+        return Response { status: 200, body: json!(years) };
+    }
+
+    fn solve(&self, year: u16, day: u8, part: u8, body: &[u8]) -> Response {
+        let Some(solution) = self.years.iter().find(|y| y.year == year).and_then(|y| y.solution(day)) else {
+            return Response::error(404, &format!("Day {day} of {year} is not implemented"));
+        };
+
+        let Ok(input) = String::from_utf8(body.to_vec()) else {
+            return Response::error(400, "The input is not valid UTF-8");
+        };
+
+        let solve = move || runner::run_day(solution, &[part], Ok(input)).parts.into_iter().next().map(|(_, r)| r);
+
+        let result = match self.run(solve) {
+            Ok(result) => result,
+            Err(response) => return response,
+        };
+
+        return match result {
+            Ok(run) => Response { status: 200, body: json!(Solved { year, record: Record::new(day, part, run) }) },
+            Err(failure) => Response::failure(&failure),
+        };
+    }
+
+    /// Solves a part with `solve` on a thread of its own, giving up on it once it takes longer than the timeout.
+    ///
+    /// Threads can not be stopped, so a part that timed out keeps running and counts towards the parts running at
+    /// once until it finishes. This keeps inputs that take forever from piling up.
+    fn run(
+        &self,
+        solve: impl FnOnce() -> Option<Result<Run, Failure>> + Send + 'static,
+    ) -> Result<Result<Run, Failure>, Response> {
+        if self.running.fetch_add(1, Ordering::SeqCst) >= self.limits.max_running {
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            return Err(Response::busy("Too many parts are running"));
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        let running = Arc::clone(&self.running);
+
+        thread::spawn(move || {
+            let result = solve();
+            running.fetch_sub(1, Ordering::SeqCst);
+
+            let _ = sender.send(result);
+        });
+
+        return match receiver.recv_timeout(self.limits.timeout) {
+            Ok(Some(result)) => Ok(result),
+            Ok(None) => Err(Response::error(500, "The part did not run")),
+            Err(_) => Err(Response {
+                status: 504,
+                body: json!({
+                    "error": format!("Solving took longer than {}", format_duration(self.limits.timeout)),
+                    "kind": "timeout",
+                }),
+            }),
+        };
+    }
+}
+
+/// Reads the request line and the headers, up to [MAX_HEAD] bytes.
+fn read_head(reader: &mut impl BufRead) -> Result<Head, Response> {
+    let mut reader = reader.take(MAX_HEAD);
+    let mut lines = Vec::new();
+
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).map_err(|e| read_error(e, "the request line and headers"))?;
+
+        if !line.ends_with('\n') && reader.limit() == 0 {
+            return Err(Response::error(431, "The request line and headers are too large"));
+        }
+
+        if !line.ends_with('\n') {
+            return Err(Response::error(400, "The request ended early"));
+        }
+
+        let line = line.trim_end();
+
+        if line.is_empty() {
+            break;
+        }
+
+        lines.push(String::from(line));
+    }
+
+    let mut request_line = lines.first().map(|line| line.split(' ')).into_iter().flatten();
+    let (Some(method), Some(path), Some(_)) = (request_line.next(), request_line.next(), request_line.next()) else {
+        return Err(Response::error(400, "Invalid request line"));
+    };
+
+    let headers = lines[1..].iter()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(key, value)| (String::from(key.trim()), String::from(value.trim())))
+        .collect();
+
+    return Ok(Head { method: String::from(method), path: String::from(path), headers });
+}
+
+fn read_error(error: io::Error, what: &str) -> Response {
+    if matches!(error.kind(), ErrorKind::TimedOut | ErrorKind::WouldBlock) {
+        return Response::error(408, &format!("Timed out reading {what}"));
+    }
+
+    return Response::error(400, &format!("Unable to read {what}: {error}"));
+}
+
+/// A connection whose reads and writes fail once `deadline` has passed. Timeouts of single reads alone would let a
+/// client sending a byte at a time keep the connection open forever.
+struct Deadline<'a> {
+    stream: &'a TcpStream,
+    deadline: Instant,
+}
+
+impl Deadline<'_> {
+    fn remaining(&self) -> io::Result<Duration> {
+        let remaining = self.deadline.saturating_duration_since(Instant::now());
+
+        if remaining.is_zero() {
+            return Err(io::Error::new(ErrorKind::TimedOut, "The deadline has passed"));
+        }
+
+        return Ok(remaining);
+    }
+}
+
+impl Read for Deadline<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.stream.set_read_timeout(Some(self.remaining()?))?;
+        return self.stream.read(buf);
+    }
+}
+
+impl Write for Deadline<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.stream.set_write_timeout(Some(self.remaining()?))?;
+        return self.stream.write(buf);
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        return self.stream.flush();
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    return match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        411 => "Length Required",
+        413 => "Content Too Large",
+        422 => "Unprocessable Content",
+        431 => "Request Header Fields Too Large",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => "",
+    };
+}
+
+fn write_response(writer: &mut impl Write, response: &Response) -> io::Result<()> {
+    let body = serde_json::to_string_pretty(&response.body).unwrap() + "\n";
+
+    write!(
+        writer,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        response.status,
+        reason(response.status),
+        body.len(),
+    )?;
+
+    return writer.flush();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2022;
+
+    use super::*;
+
+    static YEARS: [&Year; 1] = [&y2022::YEAR];
+
+    fn limits() -> Limits {
+        return Limits {
+            max_body: 100,
+            timeout: Duration::from_secs(10),
+            request_timeout: Duration::from_secs(10),
+            max_running: 2,
+            max_connections: 2,
+        };
+    }
+
+    fn server() -> Server {
+        return Server::new(&YEARS, limits());
+    }
+
+    fn request(method: &str, path: &str, body: &str) -> Request {
+        return Request { method: String::from(method), path: String::from(path), body: body.as_bytes().to_vec() };
+    }
+
+    #[test]
+    fn lists_days() {
+        let response = server().respond(&request("GET", "/days", ""));
+
+        let days: Vec<u8> = y2022::YEAR.solutions.iter().map(|s| s.day()).collect();
+
+        assert_eq!(200, response.status);
+        assert_eq!(json!([{"year": 2022, "days": days}]), response.body);
+    }
+
+    #[test]
+    fn solves_part() {
+        let response = server().respond(&request("POST", "/2022/day/6/part/2", "mjqjpqmgbljsphdztnvjfqwrcgsmlb"));
+
+        assert_eq!(200, response.status);
+        assert_eq!(json!(2022), response.body["year"]);
+        assert_eq!(json!(6), response.body["day"]);
+        assert_eq!(json!(2), response.body["part"]);
+        assert_eq!(json!(19), response.body["answer"]);
+        assert!(response.body["solve_time_ns"].is_u64());
+    }
+
+    #[test]
+    fn parse_error() {
+        let response = server().respond(&request("POST", "/2022/day/2/part/1", "A Y\nB Q\n"));
+
+        assert_eq!(422, response.status);
+        assert_eq!(json!("parse"), response.body["kind"]);
+        assert_eq!(json!(2), response.body["line"]);
+        assert_eq!(json!(3), response.body["column"]);
+    }
+
+    #[test]
+    fn unknown_or_invalid_parts() {
+        let server = server();
+
+        assert_eq!(404, server.respond(&request("POST", "/2022/day/25/part/1", "")).status);
+        assert_eq!(404, server.respond(&request("POST", "/2015/day/1/part/1", "")).status);
+        assert_eq!(404, server.respond(&request("POST", "/2022/day/1/part/3", "")).status);
+        assert_eq!(404, server.respond(&request("GET", "/", "")).status);
+        assert_eq!(405, server.respond(&request("GET", "/2022/day/1/part/1", "")).status);
+        assert_eq!(405, server.respond(&request("POST", "/days", "")).status);
+
+        let invalid_utf8 = Request { body: vec![0xff], ..request("POST", "/2022/day/1/part/1", "") };
+        assert_eq!(400, server.respond(&invalid_utf8).status);
+    }
+
+    #[test]
+    fn reads_request() {
+        let mut input = "POST /2022/day/1/part/1 HTTP/1.1\r\nHost: x\r\ncontent-length: 3\r\n\r\n1\n2".as_bytes();
+        let mut output = Vec::new();
+
+        assert_eq!(
+            Ok(request("POST", "/2022/day/1/part/1", "1\n2")),
+            server().read_request(&mut input, &mut output)
+        );
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn continues_if_expected() {
+        let mut input = "POST /a HTTP/1.1\r\nContent-Length: 1\r\nExpect: 100-continue\r\n\r\n1".as_bytes();
+        let mut output = Vec::new();
+
+        assert_eq!(Ok(request("POST", "/a", "1")), server().read_request(&mut input, &mut output));
+        assert_eq!(b"HTTP/1.1 100 Continue\r\n\r\n".to_vec(), output);
+    }
+
+    #[test]
+    fn rejects_large_or_invalid_requests() {
+        let status = |input: &str| server().read_request(&mut input.as_bytes(), &mut Vec::new()).unwrap_err().status;
+
+        assert_eq!(413, status("POST /a HTTP/1.1\r\nContent-Length: 101\r\n\r\n"));
+        assert_eq!(411, status("POST /a HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n"));
+        assert_eq!(400, status("POST /a HTTP/1.1\r\nContent-Length: x\r\n\r\n"));
+        assert_eq!(400, status("POST /a HTTP/1.1\r\nContent-Length: 5\r\n\r\n1"));
+        assert_eq!(400, status("POST /a HTTP/1.1\r\n"));
+        assert_eq!(400, status("POST\r\n\r\n"));
+        assert_eq!(431, status(&format!("GET /days HTTP/1.1\r\nCookie: {}\r\n\r\n", "a".repeat(10_000))));
+    }
+
+    #[test]
+    fn busy_and_timeout() {
+        let busy = Server::new(&YEARS, Limits { max_running: 0, ..limits() });
+        let slow = Server::new(&YEARS, Limits { timeout: Duration::from_millis(1), max_running: 1, ..limits() });
+
+        assert_eq!(503, busy.respond(&request("POST", "/2022/day/6/part/1", "abcd")).status);
+
+        // The part only finishes once it is released, so it times out and keeps running until then
+        let (release, released) = mpsc::channel::<()>();
+        let part = move || {
+            let _ = released.recv();
+            return None;
+        };
+
+        assert_eq!(504, slow.run(part).unwrap_err().status);
+        assert_eq!(503, slow.run(|| None).unwrap_err().status);
+
+        // Once released, the part no longer counts towards the parts running at once
+        drop(release);
+
+        while slow.running.load(Ordering::SeqCst) > 0 {
+            thread::yield_now();
+        }
+    }
+
+    #[test]
+    fn times_out_reading_requests_sent_a_byte_at_a_time() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (stream, _) = listener.accept().unwrap();
+
+        // Each byte arrives long before a single read would time out, but the request never ends
+        let sender = thread::spawn(move || {
+            let mut bytes = b"GET /days HTTP/1.1\r\nX-Slow: ".iter().chain(std::iter::repeat(&b'a'));
+            while client.write_all(&[*bytes.next().unwrap()]).is_ok() {
+                thread::sleep(Duration::from_millis(5));
+            }
+        });
+
+        let deadline = Instant::now() + Duration::from_millis(100);
+        let mut reader = BufReader::new(Deadline { stream: &stream, deadline });
+        let result = server().read_request(&mut reader, &mut Vec::new());
+
+        assert_eq!(408, result.unwrap_err().status);
+        drop(stream);
+        sender.join().unwrap();
+    }
+
+    #[test]
+    fn response() {
+        let mut output = Vec::new();
+        write_response(&mut output, &Response::error(404, "No")).unwrap();
+
+        assert_eq!(
+            "HTTP/1.1 404 Not Found\r\nContent-Type: application/json\r\nContent-Length: 20\r\n\
+            Connection: close\r\n\r\n{\n  \"error\": \"No\"\n}\n",
+            String::from_utf8(output).unwrap()
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::parse::ParseError;
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Answer {
+    /// Representation on a single line, quoting and escaping multi-line text.
+    pub fn inline(&self) -> String {
+        return match self {
+            Answer::Text(text) if text.contains('\n') => format!("{text:?}"),
+            _ => self.to_string(),
+        };
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        return match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        };
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        return Answer::Number(value as i64);
+    }
+}
+
+impl From<u16> for Answer {
+    fn from(value: u16) -> Self {
+        return Answer::Number(value as i64);
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        return Answer::Number(value as i64);
+    }
+}
+
+/// Numbers too large for an `i64` are kept as text instead of wrapping around.
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        return i64::try_from(value).map_or_else(|_| Answer::Text(value.to_string()), Answer::Number);
+    }
+}
+
+/// Numbers too large for an `i64` are kept as text instead of wrapping around.
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        return i64::try_from(value).map_or_else(|_| Answer::Text(value.to_string()), Answer::Number);
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        return Answer::Text(value);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Error {
+    /// The input does not match the format of the puzzle.
+    Parse(ParseError),
+    /// The input is well-formed, but has no answer.
+    Unsolvable(String),
+    /// The input could not be read while solving, see [crate::stream].
+    Read(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        return match self {
+            Error::Parse(error) => write!(f, "{error}"),
+            Error::Unsolvable(message) | Error::Read(message) => write!(f, "{message}"),
+        };
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        return Error::Parse(error);
+    }
+}
+
+/// The puzzle of a day, split into parsing the input and solving the parts on the parsed input.
+pub trait Puzzle: Sync {
+    type Input<'a>;
+
+    fn day(&self) -> u8;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError>;
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error>;
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error>;
+
+    /// Every problem of the input with the grammar of [Puzzle::parse], which stops at the first one. Days carrying on
+    /// after a problem override this, by default only the problem [Puzzle::parse] stops at is found.
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        return self.parse(input).err().into_iter().collect();
+    }
+}
+
+/// Answer of a part along with the time it took to parse the input and to solve the part.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Run {
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// Parses an input and solves a part of it, measuring both.
+pub(crate) fn run<I>(
+    parse: impl FnOnce() -> Result<I, ParseError>,
+    solve: impl FnOnce(&I) -> Result<Answer, Error>,
+) -> Result<Run, Error> {
+    let start = Instant::now();
+    let parsed = parse()?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let answer = solve(&parsed)?;
+    let solve_time = start.elapsed();
+
+    return Ok(Run { answer, parse_time, solve_time });
+}
+
+/// Object safe counterpart of [Puzzle], implemented for every puzzle.
+pub trait Solution: Sync {
+    fn day(&self) -> u8;
+
+    fn run(&self, part: u8, input: &str) -> Result<Run, Error>;
+
+    /// Every problem of the input, ordered by position, see [Puzzle::check].
+    fn check(&self, input: &str) -> Vec<ParseError>;
+
+    fn part(&self, part: u8, input: &str) -> Result<Answer, Error> {
+        return Ok(self.run(part, input)?.answer);
+    }
+}
+
+impl<P: Puzzle> Solution for P {
+    fn day(&self) -> u8 {
+        return Puzzle::day(self);
+    }
+
+    fn run(&self, part: u8, input: &str) -> Result<Run, Error> {
+        return run(|| self.parse(input), |parsed| match part {
+            1 => self.part_one(parsed),
+            2 => self.part_two(parsed),
+            _ => panic!("Invalid part {part}"),
+        });
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        let mut problems = Puzzle::check(self, input);
+        problems.sort_by_key(|problem| (problem.line, problem.column));
+        problems.dedup();
+
+        return problems;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_are_displayed_as_is() {
+        assert_eq!("-42", Answer::from(-42).to_string());
+        assert_eq!("2713310158", Answer::from(2713310158_u64).to_string());
+    }
+
+    #[test]
+    fn numbers_too_large_for_i64_are_text() {
+        assert_eq!(Answer::Number(i64::MAX), Answer::from(i64::MAX as u64));
+        assert_eq!(Answer::Text(String::from("18446744073709551615")), Answer::from(u64::MAX));
+        assert_eq!(Answer::from(usize::MAX as u64), Answer::from(usize::MAX));
+    }
+
+    #[test]
+    fn text_is_displayed_as_is() {
+        assert_eq!("CMZ", Answer::from(String::from("CMZ")).to_string());
+    }
+
+    #[test]
+    fn multi_line_text_is_quoted_inline() {
+        assert_eq!("\"##..\\n.##.\\n\"", Answer::from(String::from("##..\n.##.\n")).inline());
+        assert_eq!("CMZ", Answer::from(String::from("CMZ")).inline());
+    }
+}
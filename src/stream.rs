@@ -0,0 +1,83 @@
+use std::io::BufRead;
+
+use crate::parse::Scanner;
+use crate::solution::{Answer, Error, Puzzle};
+
+/// A puzzle that can be solved while reading its input, without holding all of it in memory.
+pub trait Stream: Puzzle {
+    /// Solves a part in a single pass over the input, keeping only what the part needs to remember.
+    fn stream(&self, part: u8, input: &mut dyn BufRead) -> Result<Answer, Error>;
+}
+
+/// Object safe counterpart of [Stream], implemented for every streaming puzzle.
+pub trait Streaming: Sync {
+    fn day(&self) -> u8;
+
+    fn stream(&self, part: u8, input: &mut dyn BufRead) -> Result<Answer, Error>;
+}
+
+impl<S: Stream> Streaming for S {
+    fn day(&self) -> u8 {
+        return Puzzle::day(self);
+    }
+
+    fn stream(&self, part: u8, input: &mut dyn BufRead) -> Result<Answer, Error> {
+        return Stream::stream(self, part, input);
+    }
+}
+
+/// Reads an input line by line into the same buffer, wrapping every line into a [Scanner] like
+/// [crate::parse::Lines] does.
+pub struct LineReader<'r> {
+    input: &'r mut dyn BufRead,
+    buffer: String,
+    line: usize,
+}
+
+impl<'r> LineReader<'r> {
+    pub fn new(input: &'r mut dyn BufRead) -> LineReader<'r> {
+        return LineReader { input, buffer: String::new(), line: 0 };
+    }
+
+    /// Returns the next line without its line break, or `None` at the end of the input.
+    pub fn next_line(&mut self) -> Result<Option<Scanner<'_>>, Error> {
+        self.buffer.clear();
+
+        let read = self.input.read_line(&mut self.buffer)
+            .map_err(|e| Error::Read(format!("Unable to read line {}: {e}", self.line + 1)))?;
+
+        if read == 0 {
+            return Ok(None);
+        }
+
+        self.line += 1;
+        let text = self.buffer.strip_suffix('\n').unwrap_or(&self.buffer);
+
+        return Ok(Some(Scanner::new(self.line, text.strip_suffix('\r').unwrap_or(text))));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_lines_like_lines_of_str() {
+        for input in ["a\nb", "a\r\nb\n", "\n\nc", ""] {
+            let mut bytes = input.as_bytes();
+            let mut reader = LineReader::new(&mut bytes);
+            let mut lines = Vec::new();
+
+            while let Some(line) = reader.next_line().unwrap() {
+                lines.push((line.line(), line.text().to_string()));
+            }
+
+            let expected: Vec<(usize, String)> = input.lines()
+                .enumerate()
+                .map(|(i, l)| (i + 1, l.to_string()))
+                .collect();
+
+            assert_eq!(expected, lines, "{input:?}");
+        }
+    }
+}
@@ -0,0 +1,360 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::solution::Answer;
+
+/// Time to wait after a wrong answer, the shortest penalty given by the website.
+pub const WRONG_ANSWER_PENALTY: u64 = 60;
+
+/// Outcome of a submitted answer, as reported by the response page.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// The answer was not checked, because the part is already solved or the previous part is not.
+    WrongLevel,
+    /// The answer was not checked, because the previous one was given too recently.
+    Wait(u64),
+}
+
+impl Outcome {
+    /// Finds the outcome in the text of the response page.
+    pub fn parse(page: &str) -> Option<Outcome> {
+        let text = article_text(page);
+
+        if text.contains("That's the right answer") {
+            return Some(Outcome::Correct);
+        }
+
+        if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                return Some(Outcome::TooHigh);
+            }
+
+            if text.contains("your answer is too low") {
+                return Some(Outcome::TooLow);
+            }
+
+            return Some(Outcome::Incorrect);
+        }
+
+        if text.contains("You don't seem to be solving the right level") {
+            return Some(Outcome::WrongLevel);
+        }
+
+        if text.contains("You gave an answer too recently") {
+            return Some(Outcome::Wait(seconds_left_to_wait(&text)?));
+        }
+
+        return None;
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        return matches!(self, Outcome::Incorrect | Outcome::TooHigh | Outcome::TooLow);
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        return match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Incorrect => write!(f, "incorrect"),
+            Outcome::TooHigh => write!(f, "incorrect, too high"),
+            Outcome::TooLow => write!(f, "incorrect, too low"),
+            Outcome::WrongLevel => write!(f, "not checked, the part is already solved or the previous one is not"),
+            Outcome::Wait(seconds) => write!(f, "not checked, wait {seconds}s before submitting again"),
+        };
+    }
+}
+
+/// Text of the `<article>` of a page with the tags stripped, or of the whole page if it has none.
+fn article_text(page: &str) -> String {
+    let article = match (page.find("<article"), page.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &page[start..end],
+        _ => page,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    return text;
+}
+
+/// Parses the time in "You have 1m 20s left to wait".
+fn seconds_left_to_wait(text: &str) -> Option<u64> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    return text[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let number = number.parse::<u64>().ok()?;
+
+            return match unit {
+                "h" => Some(number * 3600),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            };
+        })
+        .sum();
+}
+
+/// An answer as it was submitted, along with its outcome and the time of submission in seconds since the epoch.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct Submission {
+    pub answer: Answer,
+    pub outcome: Outcome,
+    pub at: u64,
+}
+
+impl Submission {
+    /// Earliest time the next answer may be submitted.
+    fn next_allowed(&self) -> u64 {
+        return match self.outcome {
+            Outcome::Wait(seconds) => self.at + seconds,
+            outcome if outcome.is_wrong() => self.at + WRONG_ANSWER_PENALTY,
+            _ => self.at,
+        };
+    }
+}
+
+/// Reason to not submit an answer at all.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Refusal {
+    AlreadySolved(Answer),
+    KnownWrong(Submission),
+    /// Answer is out of the bounds given by previous answers that were too high or too low.
+    OutOfBounds(Submission),
+    Throttled(u64),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        return match self {
+            Refusal::AlreadySolved(answer) => write!(f, "The part is already solved with {answer}"),
+            Refusal::KnownWrong(submission) => {
+                write!(f, "{} was already submitted and is {}", submission.answer, submission.outcome)
+            }
+            Refusal::OutOfBounds(submission) => {
+                write!(f, "The answer must be wrong, since {} is {}", submission.answer, submission.outcome)
+            }
+            Refusal::Throttled(seconds) => write!(f, "Wait {seconds}s before submitting another answer"),
+        };
+    }
+}
+
+/// Local record of the submitted answers, by day and part.
+///
+/// Stored as TOML with an array of submissions per part:
+///
+/// ```toml
+/// [[day07.part_one]]
+/// answer = 95437
+/// outcome = "correct"
+/// at = 1670400000
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Submissions {
+    days: BTreeMap<String, BTreeMap<String, Vec<Submission>>>,
+}
+
+impl Submissions {
+    /// Loads the submissions from a file, which is treated as empty if it does not exist.
+    pub fn load(path: &Path) -> Result<Submissions, String> {
+        return match fs::read_to_string(path) {
+            Ok(toml) => toml::from_str(&toml).map_err(|e| format!("Invalid submissions file {}: {e}", path.display())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Submissions::default()),
+            Err(e) => Err(format!("Unable to read {}: {e}", path.display())),
+        };
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let toml = toml::to_string(self).unwrap();
+
+        return fs::create_dir_all(path.parent().unwrap_or(Path::new("")))
+            .and_then(|_| fs::write(path, toml))
+            .map_err(|e| format!("Unable to write {}: {e}", path.display()));
+    }
+
+    pub fn of(&self, day: u8, part: u8) -> &[Submission] {
+        return self.days.get(&day_key(day)).and_then(|parts| parts.get(part_key(part))).map_or(&[], |s| s);
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, submission: Submission) {
+        self.days.entry(day_key(day)).or_default().entry(String::from(part_key(part))).or_default().push(submission);
+    }
+
+    /// Checks whether an answer may be submitted at `now`, in seconds since the epoch.
+    pub fn check(&self, day: u8, part: u8, answer: &Answer, now: u64) -> Result<(), Refusal> {
+        let submissions = self.of(day, part);
+
+        if let Some(correct) = submissions.iter().find(|s| s.outcome == Outcome::Correct) {
+            return Err(Refusal::AlreadySolved(correct.answer.clone()));
+        }
+
+        if let Some(wrong) = submissions.iter().find(|s| s.outcome.is_wrong() && &s.answer == answer) {
+            return Err(Refusal::KnownWrong(wrong.clone()));
+        }
+
+        if let Some(bound) = submissions.iter().find(|s| is_out_of_bounds(answer, s)) {
+            return Err(Refusal::OutOfBounds(bound.clone()));
+        }
+
+        let next_allowed = self.days.values()
+            .flat_map(|parts| parts.values().flatten())
+            .map(Submission::next_allowed)
+            .max();
+
+        return match next_allowed {
+            Some(next_allowed) if next_allowed > now => Err(Refusal::Throttled(next_allowed - now)),
+            _ => Ok(()),
+        };
+    }
+}
+
+fn is_out_of_bounds(answer: &Answer, submission: &Submission) -> bool {
+    return match (answer, &submission.answer, submission.outcome) {
+        (Answer::Number(answer), Answer::Number(bound), Outcome::TooHigh) => answer >= bound,
+        (Answer::Number(answer), Answer::Number(bound), Outcome::TooLow) => answer <= bound,
+        _ => false,
+    };
+}
+
+/// Current time in seconds since the epoch.
+pub fn now() -> u64 {
+    return SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+}
+
+fn day_key(day: u8) -> String {
+    return format!("day{day:02}");
+}
+
+fn part_key(part: u8) -> &'static str {
+    return if part == 1 { "part_one" } else { "part_two" };
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn page(article: &str) -> String {
+        return format!("<html><body><main>\n<article><p>{article}</p></article>\n</main></body></html>");
+    }
+
+    #[rstest]
+    #[case("That's the right answer!  You are one gold star closer to saving your vacation.", Outcome::Correct)]
+    #[case(
+        "That's not the right answer.  If you're stuck, make sure you're using the full input data.",
+        Outcome::Incorrect
+    )]
+    #[case("That's not the right answer; your answer is too high.  Please wait one minute.", Outcome::TooHigh)]
+    #[case("That's not the right answer; your answer is too low.  Please wait one minute.", Outcome::TooLow)]
+    #[case("You don't seem to be solving the right level.  Did you already complete it?", Outcome::WrongLevel)]
+    #[case(
+        "You gave an answer too recently; you have to wait after submitting an answer before trying again.  \
+            You have 34s left to wait.",
+        Outcome::Wait(34)
+    )]
+    #[case(
+        "You gave an answer too recently; you have to wait after submitting an answer before trying again.  \
+            You have 4m 5s left to wait.",
+        Outcome::Wait(245)
+    )]
+    fn parse_outcome(#[case] article: &str, #[case] expected: Outcome) {
+        assert_eq!(Some(expected), Outcome::parse(&page(article)));
+    }
+
+    #[test]
+    fn parse_outcome_ignores_tags() {
+        let article = "That's not the right answer; your answer is too <em>high</em>.";
+
+        assert_eq!(Some(Outcome::TooHigh), Outcome::parse(&page(article)));
+        assert_eq!(None, Outcome::parse(&page("Something else")));
+    }
+
+    fn submission(answer: i64, outcome: Outcome, at: u64) -> Submission {
+        return Submission { answer: Answer::Number(answer), outcome, at };
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let mut submissions = Submissions::default();
+        submissions.record(7, 1, submission(1000, Outcome::Incorrect, 100));
+        submissions.record(7, 1, submission(5000, Outcome::TooHigh, 200));
+        submissions.record(7, 1, submission(10, Outcome::TooLow, 300));
+
+        assert_eq!(
+            Err(Refusal::KnownWrong(submission(1000, Outcome::Incorrect, 100))),
+            submissions.check(7, 1, &Answer::Number(1000), 1000)
+        );
+        assert_eq!(
+            Err(Refusal::OutOfBounds(submission(5000, Outcome::TooHigh, 200))),
+            submissions.check(7, 1, &Answer::Number(6000), 1000)
+        );
+        assert_eq!(
+            Err(Refusal::OutOfBounds(submission(10, Outcome::TooLow, 300))),
+            submissions.check(7, 1, &Answer::Number(5), 1000)
+        );
+        assert_eq!(Ok(()), submissions.check(7, 1, &Answer::Number(2000), 1000));
+        assert_eq!(Ok(()), submissions.check(7, 2, &Answer::Number(1000), 1000));
+    }
+
+    #[test]
+    fn refuses_answers_of_solved_parts() {
+        let mut submissions = Submissions::default();
+        submissions.record(7, 1, submission(95437, Outcome::Correct, 100));
+
+        assert_eq!(
+            Err(Refusal::AlreadySolved(Answer::Number(95437))),
+            submissions.check(7, 1, &Answer::Number(1), 1000)
+        );
+    }
+
+    #[test]
+    fn throttles_after_wrong_answers_and_waits() {
+        let mut submissions = Submissions::default();
+        submissions.record(5, 1, submission(1, Outcome::Incorrect, 100));
+
+        assert_eq!(Err(Refusal::Throttled(20)), submissions.check(7, 1, &Answer::Number(2), 140));
+        assert_eq!(Ok(()), submissions.check(7, 1, &Answer::Number(2), 160));
+
+        submissions.record(7, 1, submission(2, Outcome::Wait(300), 160));
+
+        assert_eq!(Err(Refusal::Throttled(100)), submissions.check(7, 1, &Answer::Number(2), 360));
+    }
+
+    #[test]
+    fn round_trips_through_toml() {
+        let mut submissions = Submissions::default();
+        submissions.record(7, 1, submission(1000, Outcome::TooLow, 100));
+        submissions.record(7, 1, submission(2000, Outcome::Wait(30), 130));
+        let answer = Answer::from(String::from("MCD"));
+        submissions.record(5, 2, Submission { answer, outcome: Outcome::Correct, at: 200 });
+
+        assert_eq!(submissions, toml::from_str(&toml::to_string(&submissions).unwrap()).unwrap());
+    }
+}
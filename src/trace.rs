@@ -0,0 +1,167 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Something a solver did on its way to the answer, like a monkey throwing an item, named after the function it
+/// happened in.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Event {
+    pub name: &'static str,
+    pub fields: Vec<(&'static str, String)>,
+}
+
+impl Event {
+    pub fn field(&self, name: &str) -> Option<&str> {
+        return self.fields.iter().find(|(field, _)| *field == name).map(|(_, value)| value.as_str());
+    }
+}
+
+impl fmt::Display for Event {
+    /// Renders the event on one line with its single-line fields as `name=value`, followed by its multi-line fields
+    /// indented below.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+
+        for (name, value) in self.fields.iter().filter(|(_, value)| !value.contains('\n')) {
+            write!(f, " {name}={value}")?;
+        }
+
+        for (name, value) in self.fields.iter().filter(|(_, value)| value.contains('\n')) {
+            write!(f, "\n  {name}:")?;
+
+            for line in value.lines() {
+                write!(f, "\n    {line}")?;
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+type Sink = Box<dyn FnMut(Event)>;
+
+/// Number of threads explaining, so that [enabled] is a single load while none is.
+static EXPLAINING: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static SINK: RefCell<Option<Sink>> = const { RefCell::new(None) };
+}
+
+/// Whether the events of the current thread are explained, see [explain].
+#[inline]
+pub fn enabled() -> bool {
+    return EXPLAINING.load(Ordering::Relaxed) > 0 && SINK.with(|sink| sink.borrow().is_some());
+}
+
+/// Passes an event to the sink of the current thread, if there is one. Use [trace] instead, which only builds the
+/// event if it is explained.
+pub fn emit(event: Event) {
+    SINK.with(|sink| {
+        if let Some(sink) = sink.borrow_mut().as_mut() {
+            sink(event);
+        }
+    });
+}
+
+/// Puts the previous sink back once [explain] returns or panics.
+struct Restore(Option<Sink>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        SINK.with(|sink| *sink.borrow_mut() = self.0.take());
+        EXPLAINING.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Runs `f`, passing every event it traces on the current thread to `sink`.
+pub fn explain<T>(sink: impl FnMut(Event) + 'static, f: impl FnOnce() -> T) -> T {
+    EXPLAINING.fetch_add(1, Ordering::Relaxed);
+    let _restore = Restore(SINK.with(|previous| previous.replace(Some(Box::new(sink)))));
+
+    return f();
+}
+
+/// Runs `f` and returns what it returned along with the events it traced.
+pub fn collect<T>(f: impl FnOnce() -> T) -> (T, Vec<Event>) {
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let sink = Rc::clone(&events);
+    let result = explain(move |event| sink.borrow_mut().push(event), f);
+
+    return (result, events.take());
+}
+
+/// Traces an event with the given fields, which are only converted to strings while explaining.
+///
+/// ```ignore
+/// trace!("inspections", monkey = index, worry_level = new_worry_level);
+/// ```
+macro_rules! trace {
+    ($name:literal $(, $field:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::enabled() {
+            $crate::trace::emit($crate::trace::Event {
+                name: $name,
+                fields: vec![$((stringify!($field), ($value).to_string())),*],
+            });
+        }
+    };
+}
+
+pub(crate) use trace;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn traced(values: &[u32]) -> u32 {
+        let mut sum = 0;
+
+        for value in values {
+            sum += value;
+            trace!("traced", value = value, sum = sum);
+        }
+
+        return sum;
+    }
+
+    #[test]
+    fn events_are_collected_in_order() {
+        let (sum, events) = collect(|| traced(&[1, 2]));
+
+        assert_eq!(3, sum);
+        assert_eq!(
+            vec![
+                Event { name: "traced", fields: vec![("value", String::from("1")), ("sum", String::from("1"))] },
+                Event { name: "traced", fields: vec![("value", String::from("2")), ("sum", String::from("3"))] },
+            ],
+            events
+        );
+        assert_eq!(Some("3"), events[1].field("sum"));
+    }
+
+    #[test]
+    fn nothing_is_traced_outside_of_explain() {
+        assert!(!enabled());
+        assert_eq!(6, traced(&[1, 2, 3]));
+
+        let (_, events) = collect(|| {
+            let (_, inner) = collect(|| traced(&[1]));
+            assert_eq!(1, inner.len());
+
+            return traced(&[2, 3]);
+        });
+
+        assert_eq!(2, events.len());
+        assert!(!enabled());
+    }
+
+    #[test]
+    fn multi_line_fields_are_indented() {
+        let event = Event {
+            name: "crane",
+            fields: vec![("stacks", String::from("[A]\n 1 ")), ("count", String::from("2"))],
+        };
+
+        assert_eq!("crane count=2\n  stacks:\n    [A]\n     1 ", event.to_string());
+    }
+}
@@ -0,0 +1,38 @@
+use crate::solution::{Error, Puzzle};
+
+/// State of a simulation at one point in time, rendered as text.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Frame {
+    /// What happened since the previous frame, e.g. the instruction that was carried out.
+    pub caption: String,
+    pub picture: String,
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>, picture: impl Into<String>) -> Frame {
+        return Frame { caption: caption.into(), picture: picture.into() };
+    }
+}
+
+/// A puzzle whose solution is a simulation, showing its intermediate states as frames.
+pub trait Visualize: Puzzle {
+    /// Frames of the simulation of a part, from the starting state to the final one.
+    fn frames(&self, input: &Self::Input<'_>, part: u8) -> Vec<Frame>;
+}
+
+/// Object safe counterpart of [Visualize], implemented for every visualization.
+pub trait Visualization: Sync {
+    fn day(&self) -> u8;
+
+    fn frames(&self, part: u8, input: &str) -> Result<Vec<Frame>, Error>;
+}
+
+impl<V: Visualize> Visualization for V {
+    fn day(&self) -> u8 {
+        return Puzzle::day(self);
+    }
+
+    fn frames(&self, part: u8, input: &str) -> Result<Vec<Frame>, Error> {
+        return Ok(Visualize::frames(self, &self.parse(input)?, part));
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::time::SystemTime;
+
+use serde_json::Value;
+
+use crate::fixtures;
+use crate::solution::Answer;
+
+/// Files that affect the answers of a day: its module, its input and its example fixtures.
+pub fn watched_files(root: &Path, input_dir: &Path, year: u16, day: u8) -> Vec<PathBuf> {
+    let mut files = vec![
+        root.join("src").join(format!("y{year}")).join(format!("day{day:02}.rs")),
+        input_dir.join(year.to_string()).join(format!("day{day:02}.txt")),
+    ];
+
+    let examples = root.join(fixtures::DIRECTORY).join(year.to_string()).join(format!("day{day:02}"));
+
+    if let Ok(entries) = fs::read_dir(examples) {
+        let mut examples: Vec<PathBuf> = entries.filter_map(|entry| Some(entry.ok()?.path())).collect();
+        examples.sort();
+        files.extend(examples);
+    }
+
+    return files;
+}
+
+/// Modification time and size of files, `None` for files that do not exist.
+///
+/// Comparing two snapshots taken some time apart tells which files changed in between, without relying on any
+/// platform specific file notification API.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Snapshot {
+    files: BTreeMap<PathBuf, Option<(SystemTime, u64)>>,
+}
+
+impl Snapshot {
+    pub fn take(files: &[PathBuf]) -> Snapshot {
+        let files = files.iter()
+            .map(|path| {
+                let metadata = fs::metadata(path).ok().and_then(|m| Some((m.modified().ok()?, m.len())));
+                return (path.clone(), metadata);
+            })
+            .collect();
+
+        return Snapshot { files };
+    }
+
+    /// Files that were added, removed or modified since the `previous` snapshot.
+    pub fn changes(&self, previous: &Snapshot) -> Vec<PathBuf> {
+        let mut changes: Vec<PathBuf> = self.files.iter()
+            .filter(|(path, metadata)| previous.files.get(*path) != Some(metadata))
+            .map(|(path, _)| path.clone())
+            .collect();
+
+        changes.extend(previous.files.keys().filter(|path| !self.files.contains_key(*path)).cloned());
+
+        return changes;
+    }
+}
+
+/// Answers per part from the output of `aoc run --format json`, see [crate::report::json].
+pub fn answers_from_json(json: &str) -> Result<BTreeMap<u8, Answer>, String> {
+    let records: Vec<Value> = serde_json::from_str(json).map_err(|e| format!("Invalid JSON: {e}"))?;
+
+    return records.iter()
+        .map(|record| {
+            let part = record["part"].as_u64().ok_or("Record without a part")? as u8;
+
+            let answer = match &record["answer"] {
+                Value::Number(number) => Answer::Number(number.as_i64().ok_or("Answer is not an integer")?),
+                Value::String(text) => Answer::Text(text.clone()),
+                Value::Array(rows) => Answer::Text(
+                    rows.iter().map(|row| row.as_str().unwrap_or_default().to_string() + "\n").collect()
+                ),
+                _ => return Err(String::from("Record without an answer")),
+            };
+
+            return Ok((part, answer));
+        })
+        .collect();
+}
+
+/// A line per part, comparing its answer with the one of the previous run.
+pub fn diff(previous: Option<&BTreeMap<u8, Answer>>, current: &BTreeMap<u8, Answer>) -> Vec<String> {
+    return current.iter()
+        .map(|(part, answer)| {
+            let label = if *part == 1 { "Part One" } else { "Part Two" };
+
+            return match previous.and_then(|previous| previous.get(part)) {
+                None => format!("{label}: {}", answer.inline()),
+                Some(before) if before == answer => format!("{label}: {} (unchanged)", answer.inline()),
+                Some(before) => format!("{label}: {} -> {}", before.inline(), answer.inline()),
+            };
+        })
+        .collect();
+}
+
+/// Cargo of the current toolchain, falling back to the one on the `PATH`.
+fn cargo(root: &Path) -> Command {
+    let mut command = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.current_dir(root);
+
+    return command;
+}
+
+/// Runs the example tests of the day, which are rebuilt first if the module changed.
+pub fn run_tests(root: &Path, year: u16, day: u8) -> Result<Output, String> {
+    return cargo(root)
+        .args(["test", "--quiet", "--lib", &format!("y{year}::day{day:02}::")])
+        .output()
+        .map_err(|e| format!("Unable to run cargo: {e}"));
+}
+
+/// Solves both parts of the day on its real input with a freshly built `aoc`.
+pub fn run_solve(root: &Path, input_dir: &Path, year: u16, day: u8) -> Result<Output, String> {
+    return cargo(root)
+        .args(["run", "--quiet", "--", "--year", &year.to_string(), "run", &day.to_string(), "--format", "json"])
+        .arg("--input-dir")
+        .arg(input_dir)
+        .output()
+        .map_err(|e| format!("Unable to run cargo: {e}"));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn snapshot_changes() {
+        let directory = env::temp_dir().join(format!("advent-of-rust-watch-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let files = vec![directory.join("a.txt"), directory.join("b.txt")];
+        fs::write(&files[0], "1").unwrap();
+        let _ = fs::remove_file(&files[1]);
+
+        let before = Snapshot::take(&files);
+        assert!(Snapshot::take(&files).changes(&before).is_empty());
+
+        fs::write(&files[0], "12").unwrap();
+        fs::write(&files[1], "").unwrap();
+        let after = Snapshot::take(&files);
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(files, after.changes(&before));
+        assert_eq!(
+            vec![files[1].clone(), files[0].clone()],
+            Snapshot::take(&files[1..]).changes(&Snapshot::take(&files[..1]))
+        );
+    }
+
+    #[test]
+    fn watched_files_of_day() {
+        let files = watched_files(Path::new(env!("CARGO_MANIFEST_DIR")), Path::new("input"), 2022, 6);
+
+        assert!(files[0].ends_with("src/y2022/day06.rs"));
+        assert!(files[1].ends_with("input/2022/day06.txt"));
+        assert!(files.iter().any(|path| path.ends_with("examples/2022/day06/example1.txt")));
+        assert!(files.iter().any(|path| path.ends_with("examples/2022/day06/example1.expected")));
+    }
+
+    #[test]
+    fn answers_of_json_report() {
+        let json = r###"[
+            {"day": 10, "part": 1, "answer": 13140, "parse_time_ns": 1, "solve_time_ns": 2},
+            {"day": 10, "part": 2, "answer": ["##..", "..##"], "parse_time_ns": 1, "solve_time_ns": 2}
+        ]"###;
+
+        assert_eq!(
+            BTreeMap::from([(1, Answer::Number(13140)), (2, Answer::Text(String::from("##..\n..##\n")))]),
+            answers_from_json(json).unwrap()
+        );
+    }
+
+    #[test]
+    fn diff_with_previous_answers() {
+        let previous = BTreeMap::from([(1, Answer::Number(24000)), (2, Answer::Number(41000))]);
+        let current = BTreeMap::from([(1, Answer::Number(24000)), (2, Answer::Number(45000))]);
+
+        assert_eq!(vec!["Part One: 24000", "Part Two: 45000"], diff(None, &current));
+        assert_eq!(vec!["Part One: 24000 (unchanged)", "Part Two: 41000 -> 45000"], diff(Some(&previous), &current));
+    }
+}
@@ -0,0 +1,198 @@
+use std::io::BufRead;
+
+use itertools::Itertools;
+
+use crate::params::{params, Configure};
+use crate::parse;
+use crate::parse::{Lines, ParseError, Scanner};
+use crate::solution::{Answer, Error, Puzzle};
+use crate::stream::{LineReader, Stream};
+use crate::trace::trace;
+
+params! {
+    /// Parameters of day 1, see [Configure].
+    pub struct Params {
+        /// Number of elves carrying the most calories that part two sums up
+        top: usize = 3 in 1..,
+    }
+}
+
+/// Calories of an item, `None` for the empty line between two elves.
+fn calories(mut line: Scanner) -> Result<Option<u32>, ParseError> {
+    if line.is_at_end() {
+        return Ok(None);
+    }
+
+    let calories = line.number("a number of calories")?;
+    line.end()?;
+
+    return Ok(Some(calories));
+}
+
+fn elves_from_input(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    let mut elves = vec![Vec::new()];
+
+    for line in Lines::new(input) {
+        match calories(line)? {
+            Some(calories) => elves.last_mut().unwrap().push(calories),
+            None => elves.push(Vec::new()),
+        }
+    }
+
+    return Ok(elves);
+}
+
+/// Total calories of every elf, summed up as `u64` since the items of an elf may exceed the range of `u32`.
+fn calories_per_elf(elves: &[Vec<u32>]) -> Vec<u64> {
+    return elves
+        .iter()
+        .enumerate()
+        .map(|(index, items)| {
+            let calories: u64 = items.iter().map(|&calories| calories as u64).sum();
+            trace!("calories_per_elf", elf = index + 1, calories = calories);
+            return calories;
+        })
+        .sorted()
+        .rev()
+        .collect();
+}
+
+pub fn part_one(elves: &[Vec<u32>]) -> u64 {
+    return *calories_per_elf(elves).iter().max().unwrap();
+}
+
+pub fn part_two(elves: &[Vec<u32>], params: &Params) -> u64 {
+    return calories_per_elf(elves).iter().take(params.top).sum::<u64>();
+}
+
+pub struct Day01;
+
+impl Puzzle for Day01 {
+    type Input<'a> = Vec<Vec<u32>>;
+
+    fn day(&self) -> u8 {
+        return 1;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return elves_from_input(input);
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        return parse::line_problems(input, calories);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return self.part_one_with(input, &Params::default());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return self.part_two_with(input, &Params::default());
+    }
+}
+
+impl Configure for Day01 {
+    type Params = Params;
+
+    fn part_one_with(&self, input: &Self::Input<'_>, _params: &Params) -> Result<Answer, Error> {
+        return Ok(part_one(input).into());
+    }
+
+    fn part_two_with(&self, input: &Self::Input<'_>, params: &Params) -> Result<Answer, Error> {
+        return Ok(part_two(input, params).into());
+    }
+}
+
+/// The three largest totals in descending order, keeping only those instead of sorting the totals of all elves.
+fn top_three(elves: &[Vec<u32>]) -> [u64; 3] {
+    let mut top = [0; 3];
+
+    for total in elves.iter().map(|items| items.iter().map(|&calories| calories as u64).sum()) {
+        keep_largest(&mut top, total);
+    }
+
+    return top;
+}
+
+/// Puts `total` into the three largest totals in descending order, if it is larger than the smallest of them.
+fn keep_largest<T: Ord + Copy>(top: &mut [T; 3], total: T) {
+    if total > top[2] {
+        top[2] = total;
+        top.sort_unstable_by(|a, b| b.cmp(a));
+    }
+}
+
+impl Stream for Day01 {
+    /// Keeps the total of the current elf and the three largest totals so far.
+    fn stream(&self, part: u8, input: &mut dyn BufRead) -> Result<Answer, Error> {
+        let mut lines = LineReader::new(input);
+        let mut top = [0u64; 3];
+        let mut total = 0;
+
+        while let Some(line) = lines.next_line()? {
+            match calories(line)? {
+                Some(calories) => total += calories as u64,
+                None => {
+                    keep_largest(&mut top, total);
+                    total = 0;
+                }
+            }
+        }
+
+        keep_largest(&mut top, total);
+
+        return Ok(if part == 1 { top[0] } else { top.iter().sum() }.into());
+    }
+}
+
+/// Same answers as [Day01], without sorting the elves.
+pub struct Optimized;
+
+impl Puzzle for Optimized {
+    type Input<'a> = Vec<Vec<u32>>;
+
+    fn day(&self) -> u8 {
+        return 1;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return elves_from_input(input);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(top_three(input)[0].into());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(top_three(input).iter().sum::<u64>().into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_input() -> String {
+        return String::from(include_str!("../../examples/2022/day01/example.txt"));
+    }
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(24000, part_one(&elves_from_input(&example_input()).unwrap()));
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(45000, part_two(&elves_from_input(&example_input()).unwrap(), &Params::default()));
+    }
+
+    #[test]
+    fn part_two_example_with_top_two() {
+        assert_eq!(35000, part_two(&elves_from_input(&example_input()).unwrap(), &Params { top: 2 }));
+    }
+
+    #[test]
+    fn invalid_calories() {
+        assert_eq!(Err(ParseError::new(2, 3, "end of line")), elves_from_input("1000\n20x0"));
+    }
+}
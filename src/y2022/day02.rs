@@ -0,0 +1,253 @@
+use std::io::BufRead;
+
+use itertools::Itertools;
+
+use crate::parse;
+use crate::parse::{Lines, ParseError, Scanner};
+use crate::solution::{Answer, Error, Puzzle};
+use crate::stream::{LineReader, Stream};
+use crate::trace::trace;
+
+pub struct Input {
+    player: String,
+    opponent: String,
+}
+
+struct Game {
+    player: Shape,
+    opponent: Shape,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Shape {
+    fn wins_against(&self) -> Shape {
+        return match *self {
+            Shape::Rock => Shape::Scissors,
+            Shape::Paper => Shape::Rock,
+            Shape::Scissors => Shape::Paper,
+        };
+    }
+
+    fn loses_against(&self) -> Shape {
+        return match *self {
+            Shape::Scissors => Shape::Rock,
+            Shape::Rock => Shape::Paper,
+            Shape::Paper => Shape::Scissors,
+        };
+    }
+}
+
+fn game_from_input_for_part_one(input: &Input) -> Game {
+    let opponent = match input.opponent.as_str() {
+        "A" => Shape::Rock,
+        "B" => Shape::Paper,
+        "C" => Shape::Scissors,
+        _ => panic!("Invalid value {}", input.opponent)
+    };
+
+    let player = match input.player.as_str() {
+        "X" => Shape::Rock,
+        "Y" => Shape::Paper,
+        "Z" => Shape::Scissors,
+        _ => panic!("Invalid value {}", input.player)
+    };
+
+    return Game { opponent, player };
+}
+
+fn game_from_input_for_part_two(input: &Input) -> Game {
+    let opponent = match input.opponent.as_str() {
+        "A" => Shape::Rock,
+        "B" => Shape::Paper,
+        "C" => Shape::Scissors,
+        _ => panic!("Invalid value {}", input.opponent)
+    };
+
+    let player = match input.player.as_str() {
+        "X" => opponent.wins_against(),
+        "Y" => opponent,
+        "Z" => opponent.loses_against(),
+        _ => panic!("Invalid value {}", input.player)
+    };
+
+    return Game { opponent, player };
+}
+
+fn player_score(game: &Game) -> u64 {
+    let points = match game.player {
+        Shape::Rock => 1,
+        Shape::Paper => 2,
+        Shape::Scissors => 3,
+    };
+
+    if game.player == game.opponent {
+        return points + 3;
+    }
+
+    if game.player.wins_against() == game.opponent {
+        return points + 6;
+    }
+
+    return points;
+}
+
+fn player_total_score(games: Vec<Game>) -> u64 {
+    return games.iter()
+        .map(|game| {
+            let score = player_score(game);
+            trace!(
+                "player_score",
+                opponent = format!("{:?}", game.opponent),
+                player = format!("{:?}", game.player),
+                score = score,
+            );
+            return score;
+        })
+        .sum();
+}
+
+fn map_input(input: &[Input], callback: fn(&Input) -> Game) -> Vec<Game> {
+    return input.iter().map(callback).collect_vec();
+}
+
+fn round(mut line: Scanner) -> Result<Input, ParseError> {
+    let opponent = line.one_of(&["A", "B", "C"], "the opponent's shape A, B or C")?;
+    line.literal(" ")?;
+    let player = line.one_of(&["X", "Y", "Z"], "the player's response X, Y or Z")?;
+    line.end()?;
+
+    return Ok(Input { opponent: String::from(opponent), player: String::from(player) });
+}
+
+fn input_from_string(input: &str) -> Result<Vec<Input>, ParseError> {
+    return Lines::new(input).map(round).collect();
+}
+
+pub fn part_one(input: &[Input]) -> u64 {
+    return player_total_score(map_input(input, game_from_input_for_part_one));
+}
+
+pub fn part_two(input: &[Input]) -> u64 {
+    return player_total_score(map_input(input, game_from_input_for_part_two));
+}
+
+pub struct Day02;
+
+impl Puzzle for Day02 {
+    type Input<'a> = Vec<Input>;
+
+    fn day(&self) -> u8 {
+        return 2;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return input_from_string(input);
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        return parse::line_problems(input, round);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(part_one(input).into());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(part_two(input).into());
+    }
+}
+
+/// Index of a shape or an outcome: 0 for A/X, 1 for B/Y and 2 for C/Z.
+fn index(letter: &str, first: u8) -> u64 {
+    return (letter.as_bytes()[0] - first) as u64;
+}
+
+/// Score of a round from the shape of the player and the outcome, both as an index from 0 to 2.
+fn score(shape: u64, outcome: u64) -> u64 {
+    return shape + 1 + outcome * 3;
+}
+
+/// Score of a round of part one, where the second column is the shape of the player.
+fn score_of_shape(round: &Input) -> u64 {
+    let (opponent, player) = (index(&round.opponent, b'A'), index(&round.player, b'X'));
+
+    return score(player, (player + 4 - opponent) % 3);
+}
+
+/// Score of a round of part two, where the second column is the outcome.
+fn score_of_outcome(round: &Input) -> u64 {
+    let (opponent, outcome) = (index(&round.opponent, b'A'), index(&round.player, b'X'));
+
+    return score((opponent + outcome + 2) % 3, outcome);
+}
+
+impl Stream for Day02 {
+    /// Adds up the scores of the rounds as they are read.
+    fn stream(&self, part: u8, input: &mut dyn BufRead) -> Result<Answer, Error> {
+        let mut lines = LineReader::new(input);
+        let score = if part == 1 { score_of_shape } else { score_of_outcome };
+        let mut total = 0u64;
+
+        while let Some(line) = lines.next_line()? {
+            total += score(&round(line)?);
+        }
+
+        return Ok(total.into());
+    }
+}
+
+/// Same answers as [Day02], computing the scores arithmetically instead of mapping the rounds to games.
+pub struct Optimized;
+
+impl Puzzle for Optimized {
+    type Input<'a> = Vec<Input>;
+
+    fn day(&self) -> u8 {
+        return 2;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return input_from_string(input);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(input.iter().map(score_of_shape).sum::<u64>().into());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(input.iter().map(score_of_outcome).sum::<u64>().into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_input() -> String {
+        return String::from(include_str!("../../examples/2022/day02/example.txt"));
+    }
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(15, part_one(&input_from_string(&example_input()).unwrap()));
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(12, part_two(&input_from_string(&example_input()).unwrap()));
+    }
+
+    #[test]
+    fn invalid_shape() {
+        assert_eq!(
+            Some(ParseError::new(2, 3, "the player's response X, Y or Z")),
+            input_from_string("A Y\nB W").err()
+        );
+    }
+}
@@ -0,0 +1,240 @@
+use std::io::BufRead;
+
+use crate::parse;
+use crate::parse::{Lines, ParseError, Scanner};
+use crate::solution::{Answer, Error, Puzzle};
+use crate::stream::{LineReader, Stream};
+use crate::trace::trace;
+
+fn first_intersecting_character(strings: Vec<&str>) -> Result<char, String> {
+    'next_char: for c in strings[0].chars() {
+        for s in strings[1..].iter() {
+            if !s.contains(c) {
+                continue 'next_char;
+            }
+        }
+
+        trace!("first_intersecting_character", strings = strings.join(" "), item = c);
+        return Ok(c);
+    }
+
+    return Err(no_intersecting_character(&strings));
+}
+
+fn no_intersecting_character(strings: &[&str]) -> String {
+    return format!("No intersecting character found in '{}'", strings.join("', '"));
+}
+
+fn priority_sum(items: Vec<char>) -> u32 {
+    return items.iter()
+        .map(|c| {
+            let priority = (*c as u32) - if c.is_uppercase() { 38 } else { 96 };
+            trace!("priority_sum", item = c, priority = priority);
+            return priority;
+        })
+        .sum();
+}
+
+fn rucksack(mut line: Scanner<'_>) -> Result<&str, ParseError> {
+    let items = line.take_while(|c| c.is_ascii_alphabetic());
+    line.end().map_err(|_| line.error("an item type a-z or A-Z"))?;
+
+    if !items.len().is_multiple_of(2) {
+        return Err(line.error("an even number of items"));
+    }
+
+    return Ok(items);
+}
+
+fn rucksacks_from_input(input: &str) -> Result<Vec<&str>, ParseError> {
+    return Lines::new(input).map(rucksack).collect();
+}
+
+pub fn part_one(input: &[&str]) -> Result<u32, Error> {
+    return Ok(priority_sum(
+        input.iter()
+            .map(|line| {
+                let (a, b) = line.split_at(line.len() / 2);
+                return first_intersecting_character(vec![a, b]);
+            })
+            .collect::<Result<_, _>>()
+            .map_err(Error::Unsolvable)?
+    ));
+}
+
+pub fn part_two(input: &[&str]) -> Result<u32, Error> {
+    return Ok(priority_sum(
+        input.chunks(3)
+            .map(|lines| first_intersecting_character(lines.to_vec()))
+            .collect::<Result<_, _>>()
+            .map_err(Error::Unsolvable)?
+    ));
+}
+
+pub struct Day03;
+
+impl Puzzle for Day03 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn day(&self) -> u8 {
+        return 3;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return rucksacks_from_input(input);
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        return parse::line_problems(input, rucksack);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(part_one(input)?.into());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(part_two(input)?.into());
+    }
+}
+
+fn priority(item: u8) -> u32 {
+    return if item.is_ascii_lowercase() { (item - b'a' + 1) as u32 } else { (item - b'A' + 27) as u32 };
+}
+
+/// Item types as a set of bits, one per priority.
+fn item_types(items: &str) -> u64 {
+    return items.bytes().fold(0, |set, item| set | 1 << priority(item));
+}
+
+/// Priority of the first item of the first string that all strings contain, using a set of bits per string instead
+/// of searching the other strings for every item.
+fn first_intersecting_priority(strings: &[&str]) -> Result<u32, String> {
+    let common = strings.iter().fold(u64::MAX, |set, items| set & item_types(items));
+
+    return strings[0].bytes()
+        .map(priority)
+        .find(|priority| common & 1 << priority != 0)
+        .ok_or_else(|| no_intersecting_character(strings));
+}
+
+impl Stream for Day03 {
+    /// Keeps at most the rucksacks of the current group of three.
+    ///
+    /// A rucksack or group without a common item type only fails the part at the end of the input, so invalid input
+    /// after it is rejected like it is when parsing all of the input first.
+    fn stream(&self, part: u8, input: &mut dyn BufRead) -> Result<Answer, Error> {
+        let mut lines = LineReader::new(input);
+        let mut group: Vec<String> = Vec::new();
+        let mut sum: Result<u64, String> = Ok(0);
+        let badge = |group: &[String]| {
+            return first_intersecting_priority(&group.iter().map(String::as_str).collect::<Vec<_>>());
+        };
+        let add = |sum: Result<u64, String>, priority: Result<u32, String>| Ok(sum? + priority? as u64);
+
+        while let Some(line) = lines.next_line()? {
+            let items = rucksack(line)?;
+
+            if part == 1 {
+                let (a, b) = items.split_at(items.len() / 2);
+                sum = add(sum, first_intersecting_priority(&[a, b]));
+                continue;
+            }
+
+            group.push(String::from(items));
+
+            if group.len() == 3 {
+                sum = add(sum, badge(&group));
+                group.clear();
+            }
+        }
+
+        // Like [slice::chunks], an incomplete group at the end of the input is a group of its own.
+        if !group.is_empty() {
+            sum = add(sum, badge(&group));
+        }
+
+        return Ok(sum.map_err(Error::Unsolvable)?.into());
+    }
+}
+
+/// Same answers as [Day03], with the item types of a rucksack as a set of bits.
+pub struct Optimized;
+
+impl Puzzle for Optimized {
+    type Input<'a> = Vec<&'a str>;
+
+    fn day(&self) -> u8 {
+        return 3;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return rucksacks_from_input(input);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(input.iter()
+            .map(|line| {
+                let (a, b) = line.split_at(line.len() / 2);
+                return first_intersecting_priority(&[a, b]);
+            })
+            .sum::<Result<u32, String>>()
+            .map_err(Error::Unsolvable)?
+            .into());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(input.chunks(3)
+            .map(first_intersecting_priority)
+            .sum::<Result<u32, String>>()
+            .map_err(Error::Unsolvable)?
+            .into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_input() -> String {
+        return String::from(include_str!("../../examples/2022/day03/example.txt"));
+    }
+
+    #[test]
+    fn first_intersecting_character_should_return_only_the_first_if_there_are_multiple_intersections() {
+        let result = first_intersecting_character(vec!["abcd", "bcde", "cdef"]);
+
+        assert_eq!(Ok('c'), result);
+    }
+
+    #[test]
+    fn first_intersecting_character_should_fail_if_there_is_no_intersecting_character() {
+        assert_eq!(
+            Err(String::from("No intersecting character found in 'abc', 'def', 'ghi'")),
+            first_intersecting_character(vec!["abc", "def", "ghi"])
+        );
+    }
+
+    #[test]
+    fn part_one_example() {
+        let result = part_one(&rucksacks_from_input(&example_input()).unwrap());
+
+        assert_eq!(Ok(157), result);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let result = part_two(&rucksacks_from_input(&example_input()).unwrap());
+
+        assert_eq!(Ok(70), result);
+    }
+
+    #[test]
+    fn rucksacks_from_input_should_reject_invalid_item_types() {
+        assert_eq!(Err(ParseError::new(2, 3, "an item type a-z or A-Z")), rucksacks_from_input("abcd\nab1d"));
+    }
+
+    #[test]
+    fn rucksacks_from_input_should_reject_odd_number_of_items() {
+        assert_eq!(Err(ParseError::new(1, 4, "an even number of items")), rucksacks_from_input("abc"));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,211 @@
+use std::io::BufRead;
+use std::ops::RangeInclusive;
+
+use crate::parse;
+use crate::parse::{Lines, ParseError, Scanner};
+use crate::solution::{Answer, Error, Puzzle};
+use crate::stream::{LineReader, Stream};
+use crate::trace::trace;
+
+pub type Sections = RangeInclusive<u32>;
+
+fn sections(line: &mut Scanner) -> Result<Sections, ParseError> {
+    let start = line.number("the first section ID")?;
+    line.literal("-")?;
+    let column = line.column();
+    let end = line.number("the last section ID")?;
+
+    if end < start {
+        return Err(ParseError::new(line.line(), column, &format!("a last section ID of at least {start}")));
+    }
+
+    return Ok(Sections::new(start, end));
+}
+
+fn line_to_sections_pair(mut line: Scanner) -> Result<(Sections, Sections), ParseError> {
+    let a = sections(&mut line)?;
+    line.literal(",")?;
+    let b = sections(&mut line)?;
+    line.end()?;
+
+    return Ok((a, b));
+}
+
+fn is_either_contained(a: &Sections, b: &Sections) -> bool {
+    return (b.contains(a.start()) && b.contains(a.end())) ||
+        (a.contains(b.start()) && a.contains(b.end()));
+}
+
+fn is_overlapping(a: &Sections, b: &Sections) -> bool {
+    return b.contains(a.start()) || b.contains(a.end()) ||
+        a.contains(b.start()) || a.contains(b.end());
+}
+
+fn section_pairs_from_input(input: &str) -> Result<Vec<(Sections, Sections)>, ParseError> {
+    return Lines::new(input).map(line_to_sections_pair).collect();
+}
+
+fn number_of_matching_pairs(input: &[(Sections, Sections)], filter: fn(a: &Sections, b: &Sections) -> bool) -> u32 {
+    return input.iter()
+        .filter(|(a, b)| {
+            let matches = filter(a, b);
+            trace!(
+                "number_of_matching_pairs",
+                first = format!("{}-{}", a.start(), a.end()),
+                second = format!("{}-{}", b.start(), b.end()),
+                matches = matches,
+            );
+            return matches;
+        })
+        .count() as u32;
+}
+
+pub fn part_one(input: &[(Sections, Sections)]) -> u32 {
+    return number_of_matching_pairs(input, is_either_contained);
+}
+
+pub fn part_two(input: &[(Sections, Sections)]) -> u32 {
+    return number_of_matching_pairs(input, is_overlapping);
+}
+
+pub struct Day04;
+
+impl Puzzle for Day04 {
+    type Input<'a> = Vec<(Sections, Sections)>;
+
+    fn day(&self) -> u8 {
+        return 4;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return section_pairs_from_input(input);
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        return parse::line_problems(input, line_to_sections_pair);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(part_one(input).into());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(part_two(input).into());
+    }
+}
+
+impl Stream for Day04 {
+    /// Counts the matching pairs as they are read.
+    fn stream(&self, part: u8, input: &mut dyn BufRead) -> Result<Answer, Error> {
+        let mut lines = LineReader::new(input);
+        let filter = if part == 1 { is_either_contained } else { is_overlapping };
+        let mut count = 0u64;
+
+        while let Some(line) = lines.next_line()? {
+            let (a, b) = line_to_sections_pair(line)?;
+
+            if filter(&a, &b) {
+                count += 1;
+            }
+        }
+
+        return Ok(count.into());
+    }
+}
+
+/// Same answers as [Day04], comparing the bounds of the sections directly.
+pub struct Optimized;
+
+impl Puzzle for Optimized {
+    type Input<'a> = Vec<(Sections, Sections)>;
+
+    fn day(&self) -> u8 {
+        return 4;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return section_pairs_from_input(input);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(number_of_matching_pairs(input, |a, b| {
+            return (a.start() <= b.start() && b.end() <= a.end()) || (b.start() <= a.start() && a.end() <= b.end());
+        }).into());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(number_of_matching_pairs(input, |a, b| a.start() <= b.end() && b.start() <= a.end()).into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::solution::Solution;
+
+    use super::*;
+
+    fn example_input() -> String {
+        return String::from(include_str!("../../examples/2022/day04/example.txt"));
+    }
+
+    #[rstest]
+    #[case(1..=2, 3..=4, false)]
+    #[case(1..=3, 3..=4, false)]
+    #[case(4..=6, 3..=4, false)]
+    #[case(5..=6, 3..=4, false)]
+    #[case(1..=4, 3..=4, true)]
+    #[case(3..=6, 3..=4, true)]
+    #[case(3..=7, 2..=8, true)]
+    #[case(2..=4, 2..=4, true)]
+    fn is_either_contained_test(#[case] a: Sections, #[case] b: Sections, #[case] expected: bool) {
+        assert_eq!(
+            expected,
+            is_either_contained(&a, &b),
+            "{a:?} {} be contained in {b:?}", if expected { "should" } else { "should not" }
+        );
+    }
+
+    #[rstest]
+    #[case(1..=2, 3..=4, false)]
+    #[case(5..=6, 3..=4, false)]
+    #[case(1..=3, 3..=4, true)]
+    #[case(1..=5, 3..=4, true)]
+    #[case(4..=5, 3..=4, true)]
+    fn is_overlapping_test(#[case] a: Sections, #[case] b: Sections, #[case] expected: bool) {
+        assert_eq!(
+            expected,
+            is_overlapping(&a, &b),
+            "{a:?} {} overlap with {b:?}", if expected { "should" } else { "should not" }
+        );
+    }
+
+    #[test]
+    fn part_one_example() {
+        let result = part_one(&section_pairs_from_input(&example_input()).unwrap());
+
+        assert_eq!(2, result);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let result = part_two(&section_pairs_from_input(&example_input()).unwrap());
+
+        assert_eq!(4, result);
+    }
+
+    #[test]
+    fn invalid_section_pair() {
+        assert_eq!(Err(ParseError::new(2, 4, "`,`")), section_pairs_from_input("2-4,6-8\n2-3;4-5"));
+        assert_eq!(Err(ParseError::new(1, 7, "the last section ID")), section_pairs_from_input("2-4,6-x"));
+    }
+
+    #[test]
+    fn reversed_sections() {
+        let expected = ParseError::new(2, 7, "a last section ID of at least 5");
+
+        assert_eq!(Err(expected.clone()), section_pairs_from_input("2-4,6-8\n2-3,5-3"));
+        assert_eq!(vec![expected], Solution::check(&Day04, "2-4,6-8\n2-3,5-3\n"));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,460 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use itertools::Itertools;
+
+use crate::parse::{Lines, ParseError, Scanner};
+use crate::solution::{Answer, Error, Puzzle};
+use crate::trace::trace;
+use crate::visualize::{Frame, Visualize};
+
+pub type Stacks = HashMap<u32, Vec<String>>;
+
+pub struct Instruction {
+    count: u32,
+    from: u32,
+    to: u32,
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "move {} from {} to {}", self.count, self.from, self.to);
+    }
+}
+
+impl Instruction {
+    fn from_line(mut line: Scanner, stacks: usize) -> Result<Instruction, ParseError> {
+        line.literal("move ")?;
+        let count = line.number("a number of crates")?;
+        line.literal(" from ")?;
+        let from = stack_key(&mut line, stacks)?;
+        line.literal(" to ")?;
+        let to = stack_key(&mut line, stacks)?;
+        line.end()?;
+
+        return Ok(Instruction { count, from, to });
+    }
+}
+
+fn stack_key(line: &mut Scanner, stacks: usize) -> Result<u32, ParseError> {
+    let column = line.column();
+    let expected = format!("a stack between 1 and {stacks}");
+    let key = line.number(&expected)?;
+
+    if !(1..=stacks as u32).contains(&key) {
+        return Err(ParseError::new(line.line(), column, &expected));
+    }
+
+    return Ok(key);
+}
+
+/// Lines of the stack diagram, up to the empty line separating it from the moves.
+fn diagram<'a>(lines: &mut Lines<'a>) -> Result<Vec<Scanner<'a>>, ParseError> {
+    let mut diagram = Vec::new();
+
+    loop {
+        let line = lines.expect("an empty line between the stacks and the moves")?;
+
+        if line.is_at_end() {
+            return Ok(diagram);
+        }
+
+        diagram.push(line);
+    }
+}
+
+/// Line numbering the stacks at the bottom of the diagram.
+fn numbering<'a>(diagram: &mut Vec<Scanner<'a>>) -> Result<Scanner<'a>, ParseError> {
+    return diagram.pop().ok_or_else(|| ParseError::new(1, 1, "a stack diagram"));
+}
+
+/// Number of stacks, numbered from 1 in order.
+fn stack_count(mut numbers: Scanner) -> Result<usize, ParseError> {
+    let mut count = 0;
+
+    loop {
+        count += 1;
+
+        numbers.literal(" ")?;
+        numbers.literal(&count.to_string())?;
+
+        if numbers.is_at_end() || (numbers.accept(" ") && numbers.is_at_end()) {
+            return Ok(count);
+        }
+
+        numbers.literal(" ")?;
+    }
+}
+
+/// Crates of a row of the diagram along with the keys of their stacks.
+fn crate_row(mut line: Scanner, stacks: usize) -> Result<Vec<(u32, String)>, ParseError> {
+    let mut crates = Vec::new();
+
+    for key in 1..=stacks as u32 {
+        if line.is_at_end() {
+            break;
+        }
+
+        if key > 1 {
+            line.literal(" ")?;
+        }
+
+        if line.one_of(&["   ", "["], "a crate or an empty slot")? == "[" {
+            crates.push((key, String::from(line.char("a crate")?)));
+            line.literal("]")?;
+        }
+    }
+
+    line.end()?;
+
+    return Ok(crates);
+}
+
+fn stacks_from_input(mut diagram: Vec<Scanner>) -> Result<Stacks, ParseError> {
+    let count = stack_count(numbering(&mut diagram)?)?;
+    let mut stacks: Stacks = (1..=count as u32).map(|key| (key, Vec::new())).collect();
+
+    for line in diagram.into_iter().rev() {
+        for (key, string) in crate_row(line, count)? {
+            stacks.get_mut(&key).unwrap().push(string);
+        }
+    }
+
+    return Ok(stacks);
+}
+
+/// Number of crates to move, failing if the stack to move them from holds fewer than that.
+fn crates_to_move(instruction: &Instruction, available: usize) -> Result<usize, String> {
+    let count = instruction.count as usize;
+
+    if count > available {
+        return Err(format!("Unable to move {count} crate(s) from stack {} holding {available}", instruction.from));
+    }
+
+    return Ok(count);
+}
+
+fn crane_mover_9000(stacks: &mut Stacks, instruction: &Instruction) -> Result<(), String> {
+    crates_to_move(instruction, stacks[&instruction.from].len())?;
+
+    for _i in 0..instruction.count {
+        let item = stacks.get_mut(&instruction.from).unwrap().pop().unwrap();
+        stacks.get_mut(&instruction.to).unwrap().push(item);
+    }
+
+    trace!("crane_mover_9000", instruction = instruction, stacks = drawing(stacks));
+
+    return Ok(());
+}
+
+fn crane_mover_9001(stacks: &mut Stacks, instruction: &Instruction) -> Result<(), String> {
+    let from_stack = stacks.get_mut(&instruction.from).unwrap();
+    let from_size = from_stack.len() - crates_to_move(instruction, from_stack.len())?;
+
+    let items: Vec<String> = from_stack.drain(from_size..).collect();
+
+    stacks.get_mut(&instruction.to).unwrap().extend_from_slice(&items);
+    trace!("crane_mover_9001", instruction = instruction, stacks = drawing(stacks));
+
+    return Ok(());
+}
+
+fn top_item<T: AsRef<str>>(stack: &[T], key: u32) -> Result<&str, String> {
+    return stack.last().map(T::as_ref).ok_or_else(|| format!("Stack {key} is empty after the rearrangement"));
+}
+
+/// Starting stacks and the moves of the rearrangement procedure.
+pub struct Procedure {
+    stacks: Stacks,
+    instructions: Vec<Instruction>,
+}
+
+fn procedure_from_input(input: &str) -> Result<Procedure, ParseError> {
+    let mut lines = Lines::new(input);
+    let stacks = stacks_from_input(diagram(&mut lines)?)?;
+    let instructions = lines.map(|line| Instruction::from_line(line, stacks.len())).collect::<Result<_, _>>()?;
+
+    return Ok(Procedure { stacks, instructions });
+}
+
+/// Every problem of the diagram and the moves. If the stacks are not numbered correctly, the rows and the moves are
+/// checked against the number of stacks the widest line of the diagram has room for.
+fn procedure_problems(input: &str) -> Vec<ParseError> {
+    let mut lines = Lines::new(input);
+    let mut diagram = match diagram(&mut lines) {
+        Ok(diagram) => diagram,
+        Err(problem) => return vec![problem],
+    };
+
+    let width = diagram.iter().map(|line| line.text().chars().count().div_ceil(4)).max().unwrap_or(0);
+    let numbers = match numbering(&mut diagram) {
+        Ok(numbers) => numbers,
+        Err(problem) => return vec![problem],
+    };
+
+    let mut problems = Vec::new();
+    let count = stack_count(numbers).unwrap_or_else(|problem| {
+        problems.push(problem);
+        width
+    });
+
+    problems.extend(diagram.into_iter().filter_map(|line| crate_row(line, count).err()));
+    problems.extend(lines.filter_map(|line| Instruction::from_line(line, count).err()));
+
+    return problems;
+}
+
+type Crane = fn(stacks: &mut Stacks, instruction: &Instruction) -> Result<(), String>;
+
+fn process_stacks_and_return_top_items(procedure: &Procedure, processor: Crane) -> Result<String, Error> {
+    let mut stacks = procedure.stacks.clone();
+
+    for instruction in &procedure.instructions {
+        processor(&mut stacks, instruction).map_err(Error::Unsolvable)?;
+    }
+
+    return stacks.keys()
+        .sorted()
+        .map(|k| top_item(&stacks[k], *k))
+        .collect::<Result<String, String>>()
+        .map_err(Error::Unsolvable);
+}
+
+pub fn part_one(procedure: &Procedure) -> Result<String, Error> {
+    return process_stacks_and_return_top_items(procedure, crane_mover_9000);
+}
+
+pub fn part_two(procedure: &Procedure) -> Result<String, Error> {
+    return process_stacks_and_return_top_items(procedure, crane_mover_9001);
+}
+
+pub struct Day05;
+
+impl Puzzle for Day05 {
+    type Input<'a> = Procedure;
+
+    fn day(&self) -> u8 {
+        return 5;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return procedure_from_input(input);
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        return procedure_problems(input);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(part_one(input)?.into());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(part_two(input)?.into());
+    }
+}
+
+/// Renders the stacks like the drawing at the top of the input.
+fn drawing(stacks: &Stacks) -> String {
+    let height = stacks.values().map(Vec::len).max().unwrap_or(0);
+    let keys = stacks.keys().sorted().collect_vec();
+
+    let mut lines = (0..height).rev()
+        .map(|level| {
+            return keys.iter()
+                .map(|key| stacks[key].get(level).map_or(String::from("   "), |item| format!("[{item}]")))
+                .join(" ")
+                .trim_end()
+                .to_string();
+        })
+        .collect_vec();
+
+    lines.push(keys.iter().map(|key| format!(" {key} ")).join(" ").trim_end().to_string());
+
+    return lines.join("\n");
+}
+
+impl Visualize for Day05 {
+    fn frames(&self, procedure: &Procedure, part: u8) -> Vec<Frame> {
+        let crane: Crane = if part == 1 { crane_mover_9000 } else { crane_mover_9001 };
+        let mut stacks = procedure.stacks.clone();
+        let mut frames = vec![Frame::new("Starting stacks", drawing(&stacks))];
+
+        for instruction in &procedure.instructions {
+            let caption = instruction.to_string();
+
+            if let Err(message) = crane(&mut stacks, instruction) {
+                frames.push(Frame::new(format!("{caption}: {message}"), drawing(&stacks)));
+                break;
+            }
+
+            frames.push(Frame::new(caption, drawing(&stacks)));
+        }
+
+        return frames;
+    }
+}
+
+/// Same as [process_stacks_and_return_top_items], with the stacks in a vector and every move done at once.
+fn top_items_after_moves(procedure: &Procedure, reverse: bool) -> Result<String, Error> {
+    let mut stacks: Vec<Vec<&str>> = (1..=procedure.stacks.len() as u32)
+        .map(|key| procedure.stacks[&key].iter().map(String::as_str).collect())
+        .collect();
+
+    for instruction in &procedure.instructions {
+        let from = &mut stacks[instruction.from as usize - 1];
+        let count = crates_to_move(instruction, from.len()).map_err(Error::Unsolvable)?;
+
+        if instruction.from == instruction.to {
+            continue;
+        }
+
+        let mut items = from.split_off(from.len() - count);
+
+        if reverse {
+            items.reverse();
+        }
+
+        stacks[instruction.to as usize - 1].extend(items);
+    }
+
+    return stacks.iter()
+        .zip(1..)
+        .map(|(stack, key)| top_item(stack, key))
+        .collect::<Result<String, String>>()
+        .map_err(Error::Unsolvable);
+}
+
+/// Same answers as [Day05], without looking up the stacks by key and moving crates one by one.
+pub struct Optimized;
+
+impl Puzzle for Optimized {
+    type Input<'a> = Procedure;
+
+    fn day(&self) -> u8 {
+        return 5;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return procedure_from_input(input);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(top_items_after_moves(input, true)?.into());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(top_items_after_moves(input, false)?.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::Solution;
+
+    use super::*;
+
+    fn example_input() -> String {
+        return String::from(include_str!("../../examples/2022/day05/example.txt"));
+    }
+
+    #[test]
+    fn part_one_example() {
+        let result = part_one(&procedure_from_input(&example_input()).unwrap());
+
+        assert_eq!(Ok(String::from("CMZ")), result);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let result = part_two(&procedure_from_input(&example_input()).unwrap());
+
+        assert_eq!(Ok(String::from("MCD")), result);
+    }
+
+    #[test]
+    fn invalid_crate() {
+        let string = vec![
+            "    [D]",
+            "[N] (C)",
+            " 1   2",
+            "",
+            "move 1 from 2 to 1",
+        ].join("\n");
+
+        assert_eq!(Some(ParseError::new(2, 5, "a crate or an empty slot")), procedure_from_input(&string).err());
+    }
+
+    #[test]
+    fn move_from_unknown_stack() {
+        let string = vec![
+            "    [D]",
+            "[N] [C]",
+            " 1   2",
+            "",
+            "move 1 from 2 to 1",
+            "move 1 from 3 to 1",
+        ].join("\n");
+
+        assert_eq!(Some(ParseError::new(6, 13, "a stack between 1 and 2")), procedure_from_input(&string).err());
+    }
+
+    #[test]
+    fn missing_moves_separator() {
+        assert_eq!(
+            Some(ParseError::new(2, 3, "an empty line between the stacks and the moves")),
+            procedure_from_input("[A]\n 1").err()
+        );
+    }
+
+    #[test]
+    fn every_problem_is_found() {
+        let string = vec![
+            "    [D]",
+            "[N] (C)",
+            "[Z] [M] [P]",
+            " 1   2",
+            "",
+            "move 1 from 2 to 1",
+            "move 1 from 3 to 1",
+            "move x from 1 to 2",
+        ].join("\n");
+
+        assert_eq!(
+            vec![
+                ParseError::new(2, 5, "a crate or an empty slot"),
+                ParseError::new(3, 8, "end of line"),
+                ParseError::new(7, 13, "a stack between 1 and 2"),
+                ParseError::new(8, 6, "a number of crates"),
+            ],
+            Solution::check(&Day05, &string)
+        );
+    }
+
+    #[test]
+    fn unnumbered_stacks_are_as_many_as_the_diagram_is_wide() {
+        let string = vec![
+            "[N] [C] [P]",
+            " 1   2   x",
+            "",
+            "move 1 from 3 to 4",
+        ].join("\n");
+
+        assert_eq!(
+            vec![ParseError::new(2, 10, "`3`"), ParseError::new(4, 18, "a stack between 1 and 3")],
+            Solution::check(&Day05, &string)
+        );
+    }
+
+    #[test]
+    fn frames_of_example() {
+        let frames = Visualize::frames(&Day05, &procedure_from_input(&example_input()).unwrap(), 2);
+
+        assert_eq!(5, frames.len());
+        assert_eq!(Frame::new("Starting stacks", "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3"), frames[0]);
+        assert_eq!(
+            Frame::new("move 1 from 1 to 2", "        [D]\n        [N]\n        [Z]\n[M] [C] [P]\n 1   2   3"),
+            frames[4]
+        );
+    }
+}
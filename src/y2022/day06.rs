@@ -0,0 +1,301 @@
+use std::collections::HashSet;
+use std::io;
+use std::io::{BufRead, Read};
+
+use crate::params::{params, Configure};
+use crate::parse::{Lines, ParseError, Scanner};
+use crate::solution::{Answer, Error, Puzzle};
+use crate::stream::Stream;
+use crate::trace::trace;
+
+params! {
+    /// Parameters of day 6, see [Configure].
+    pub struct Params {
+        /// Length of the start-of-packet marker of part one
+        packet_marker: usize = 4 in 1..,
+        /// Length of the start-of-message marker of part two
+        message_marker: usize = 14 in 1..,
+    }
+}
+
+/// Datastream in an error message, with only its first characters if it is long, since it is a single line of any
+/// length.
+fn describe(input: &str) -> String {
+    let prefix: String = input.chars().take(20).collect();
+    let ellipsis = if prefix.len() < input.len() { "..." } else { "" };
+
+    return format!("Datastream '{prefix}{ellipsis}' of {} characters", input.len());
+}
+
+fn position_after_unique_characters(input: &str, len: usize) -> Result<usize, String> {
+    if len > input.len() {
+        return Err(format!("{} is shorter than {len} characters", describe(input)));
+    }
+
+    for index in 0..=input.len() - len {
+        let chars: HashSet<char> = HashSet::from_iter(input[index..index + len].chars());
+
+        if chars.len() == len {
+            trace!("position_after_unique_characters", marker = &input[index..index + len], position = index + len);
+            return Ok(index + len);
+        }
+    }
+
+    return Err(format!("{} does not contain a series of {len} unique characters", describe(input)));
+}
+
+fn datastream(mut line: Scanner<'_>) -> Result<&str, ParseError> {
+    let datastream = line.take_while(|c| c.is_ascii_lowercase());
+    line.end().map_err(|_| line.error("a lowercase letter"))?;
+
+    return Ok(datastream);
+}
+
+fn datastream_from_input(input: &str) -> Result<&str, ParseError> {
+    let mut lines = Lines::new(input);
+    let datastream = datastream(lines.expect("a datastream")?)?;
+
+    if let Some(line) = lines.next() {
+        return Err(line.error("end of input"));
+    }
+
+    return Ok(datastream);
+}
+
+/// Problems of the datastream and of the line following it, if any.
+fn datastream_problems(input: &str) -> Vec<ParseError> {
+    let mut lines = Lines::new(input);
+    let mut problems: Vec<ParseError> = match lines.expect("a datastream") {
+        Ok(line) => datastream(line).err().into_iter().collect(),
+        Err(problem) => vec![problem],
+    };
+
+    problems.extend(lines.next().map(|line| line.error("end of input")));
+
+    return problems;
+}
+
+pub fn part_one(datastream: &str, params: &Params) -> Result<usize, Error> {
+    return position_after_unique_characters(datastream, params.packet_marker).map_err(Error::Unsolvable);
+}
+
+pub fn part_two(datastream: &str, params: &Params) -> Result<usize, Error> {
+    return position_after_unique_characters(datastream, params.message_marker).map_err(Error::Unsolvable);
+}
+
+pub struct Day06;
+
+impl Puzzle for Day06 {
+    type Input<'a> = &'a str;
+
+    fn day(&self) -> u8 {
+        return 6;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return datastream_from_input(input);
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        return datastream_problems(input);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return self.part_one_with(input, &Params::default());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return self.part_two_with(input, &Params::default());
+    }
+}
+
+impl Configure for Day06 {
+    type Params = Params;
+
+    fn part_one_with(&self, input: &Self::Input<'_>, params: &Params) -> Result<Answer, Error> {
+        return Ok(part_one(input, params)?.into());
+    }
+
+    fn part_two_with(&self, input: &Self::Input<'_>, params: &Params) -> Result<Answer, Error> {
+        return Ok(part_two(input, params)?.into());
+    }
+}
+
+/// Same as [position_after_unique_characters], sliding the window along while remembering where every character
+/// was seen last instead of collecting every window into a set.
+fn position_after_unique_characters_sliding(input: &str, len: usize) -> Result<usize, String> {
+    if len > input.len() {
+        return Err(format!("{} is shorter than {len} characters", describe(input)));
+    }
+
+    let mut last_seen = [None; 256];
+    let mut start = 0;
+
+    for (index, c) in input.bytes().enumerate() {
+        if let Some(previous) = last_seen[c as usize] {
+            start = start.max(previous + 1);
+        }
+
+        last_seen[c as usize] = Some(index);
+
+        if index + 1 - start == len {
+            return Ok(index + 1);
+        }
+    }
+
+    return Err(format!("{} does not contain a series of {len} unique characters", describe(input)));
+}
+
+impl Stream for Day06 {
+    /// Reads the datastream byte by byte, remembering where every character was seen last like
+    /// [position_after_unique_characters_sliding] does, since the datastream is a single line of any length.
+    ///
+    /// The rest of the datastream is still read after the marker, so invalid input is rejected like by [Day06].
+    fn stream(&self, part: u8, input: &mut dyn BufRead) -> Result<Answer, Error> {
+        let len = if part == 1 { 4 } else { 14 };
+        let mut bytes = (&mut *input).bytes().peekable();
+        let mut last_seen = [None; 256];
+        let mut start = 0;
+        let mut index = 0;
+        let mut marker = None;
+        let read_error = |e: io::Error| Error::Read(format!("Unable to read the datastream: {e}"));
+
+        if bytes.peek().is_none() {
+            return Err(ParseError::new(1, 1, "a datastream").into());
+        }
+
+        while let Some(c) = bytes.next().transpose().map_err(read_error)? {
+            if c == b'\r' && matches!(bytes.peek(), Some(Ok(b'\n'))) {
+                bytes.next();
+                break;
+            }
+
+            if c == b'\n' {
+                break;
+            }
+
+            if !c.is_ascii_lowercase() {
+                return Err(ParseError::new(1, index + 1, "a lowercase letter").into());
+            }
+
+            if let Some(previous) = last_seen[c as usize] {
+                start = start.max(previous + 1);
+            }
+
+            last_seen[c as usize] = Some(index);
+            index += 1;
+
+            if marker.is_none() && index - start == len {
+                marker = Some(index);
+            }
+        }
+
+        if bytes.next().transpose().map_err(read_error)?.is_some() {
+            return Err(ParseError::new(2, 1, "end of input").into());
+        }
+
+        return match marker {
+            Some(marker) => Ok(marker.into()),
+            None if index < len => Err(Error::Unsolvable(format!("Datastream is shorter than {len} characters"))),
+            None => Err(Error::Unsolvable(format!("Datastream does not contain a series of {len} unique characters"))),
+        };
+    }
+}
+
+/// Same answers as [Day06], scanning the datastream once.
+pub struct Optimized;
+
+impl Puzzle for Optimized {
+    type Input<'a> = &'a str;
+
+    fn day(&self) -> u8 {
+        return 6;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return datastream_from_input(input);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(position_after_unique_characters_sliding(input, 4).map_err(Error::Unsolvable)?.into());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(position_after_unique_characters_sliding(input, 14).map_err(Error::Unsolvable)?.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn no_unique_characters() {
+        assert_eq!(
+            "Datastream 'aaaa' of 4 characters does not contain a series of 2 unique characters",
+            position_after_unique_characters("aaaa", 2).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn input_is_shorter_than_length() {
+        assert_eq!(
+            "Datastream 'ab' of 2 characters is shorter than 3 characters",
+            position_after_unique_characters("ab", 3).unwrap_err()
+        );
+    }
+
+    #[rstest]
+    #[case("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4, 7)]
+    #[case("bvwbjplbgvbhsrlpgdmjqwftvncz", 4, 5)]
+    #[case("nppdvjthqldpwncqszvftbrmjlhg", 4, 6)]
+    #[case("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 4, 10)]
+    #[case("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 4, 11)]
+    #[case("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14, 19)]
+    #[case("bvwbjplbgvbhsrlpgdmjqwftvncz", 14, 23)]
+    #[case("nppdvjthqldpwncqszvftbrmjlhg", 14, 23)]
+    #[case("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14, 29)]
+    #[case("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 14, 26)]
+    #[case("aabcd", 4, 5)]
+    fn position_test(#[case] input: &str, #[case] len: usize, #[case] expected: usize) {
+        assert_eq!(expected, position_after_unique_characters(input, len).unwrap());
+    }
+
+    #[test]
+    fn invalid_datastream() {
+        assert_eq!(Err(ParseError::new(1, 4, "a lowercase letter")), datastream_from_input("abcD"));
+        assert_eq!(Err(ParseError::new(2, 1, "end of input")), datastream_from_input("abcd\nefgh"));
+        assert_eq!(
+            vec![ParseError::new(1, 4, "a lowercase letter"), ParseError::new(2, 1, "end of input")],
+            datastream_problems("abcD\nefgh\nijkl")
+        );
+    }
+
+    #[test]
+    fn datastream_without_marker() {
+        let without_marker = "Datastream 'aaaaa' of 5 characters does not contain a series of 4 unique characters";
+        let too_short = "Datastream 'aaaaaaaaaaaaaaaaaaaa...' of 1000 characters is shorter than 1001 characters";
+
+        assert_eq!(
+            Err(Error::Unsolvable(String::from(without_marker))),
+            part_one(datastream_from_input("aaaaa\n").unwrap(), &Params::default())
+        );
+        assert_eq!(
+            Err(Error::Unsolvable(String::from(too_short))),
+            part_one(&"a".repeat(1000), &Params { packet_marker: 1001, ..Params::default() })
+        );
+    }
+
+    #[rstest]
+    #[case("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 1, Ok(Answer::Number(7)))]
+    #[case("mjqjpqmgbljsphdztnvjfqwrcgsmlb\r\n", 2, Ok(Answer::Number(19)))]
+    #[case("", 1, Err(ParseError::new(1, 1, "a datastream").into()))]
+    #[case("abcdeF", 1, Err(ParseError::new(1, 6, "a lowercase letter").into()))]
+    #[case("abcd\n\n", 1, Err(ParseError::new(2, 1, "end of input").into()))]
+    #[case("abc\n", 1, Err(Error::Unsolvable(String::from("Datastream is shorter than 4 characters"))))]
+    fn streamed_datastream(#[case] input: &str, #[case] part: u8, #[case] expected: Result<Answer, Error>) {
+        assert_eq!(expected, Stream::stream(&Day06, part, &mut input.as_bytes()));
+    }
+}
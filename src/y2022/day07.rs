@@ -0,0 +1,347 @@
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use itertools::Itertools;
+
+use crate::params::{params, Configure};
+use crate::parse;
+use crate::parse::{Lines, ParseError, Scanner};
+use crate::solution::{Answer, Error, Puzzle};
+use crate::stream::{LineReader, Stream};
+use crate::trace::{self, trace};
+
+fn all_directories(path: &[String]) -> Vec<String> {
+    return (0..path.len()).map(|i| path[0..i + 1].join("/")).collect();
+}
+
+/// A line of the terminal output, either a command or an entry listed by `ls`.
+pub enum Line<'a> {
+    Cd(&'a str),
+    Ls,
+    Dir(&'a str),
+    File(u32, &'a str),
+}
+
+fn terminal_line(mut line: Scanner<'_>) -> Result<Line<'_>, ParseError> {
+    if line.accept("$ cd ") {
+        return Ok(Line::Cd(line.rest("a directory name")?));
+    }
+
+    if line.accept("$ ls") {
+        line.end()?;
+        return Ok(Line::Ls);
+    }
+
+    if line.accept("dir ") {
+        return Ok(Line::Dir(line.rest("a directory name")?));
+    }
+
+    let file_size = line.number::<u32>("a command, a directory or a file size")?;
+    line.literal(" ")?;
+
+    return Ok(Line::File(file_size, line.rest("a file name")?));
+}
+
+fn terminal_output(input: &str) -> Result<Vec<Line<'_>>, ParseError> {
+    return Lines::new(input).map(terminal_line).collect();
+}
+
+params! {
+    /// Parameters of day 7, see [Configure].
+    pub struct Params {
+        /// Size below which part one counts a directory as small
+        small_directory: u64 = 100000 in 1..,
+        /// Total size of the disk
+        disk_size: u64 = 70000000 in 1..,
+        /// Unused space the update needs
+        required_space: u64 = 30000000 in 1..,
+    }
+}
+
+/// Leaves a directory, staying in the root directory like `cd ..` in a shell does.
+fn leave<T>(path: &mut Vec<T>) {
+    if path.len() > 1 {
+        path.pop();
+    }
+}
+
+/// Size of the smallest directory freeing up at least `missing` space when deleted.
+///
+/// The root directory is large enough unless the update needs more space than the whole disk has.
+fn smallest_to_free(sizes: impl Iterator<Item = u64>, missing: u64) -> Result<u64, Error> {
+    return sizes.filter(|size| *size >= missing)
+        .min()
+        .ok_or_else(|| Error::Unsolvable(format!("No directory frees up the missing space of {missing}")));
+}
+
+/// Space that has to be freed up for the update, given the size of the root directory.
+fn space_to_free(used: u64, params: &Params) -> Result<u64, Error> {
+    if used > params.disk_size {
+        return Err(Error::Unsolvable(format!(
+            "The files take up {used}, more than the disk size of {}",
+            params.disk_size
+        )));
+    }
+
+    let unused = params.disk_size - used;
+
+    if unused >= params.required_space {
+        return Err(Error::Unsolvable(format!("The unused space of {unused} is enough for the update already")));
+    }
+
+    return Ok(params.required_space - unused);
+}
+
+fn directory_sizes(output: &[Line]) -> HashMap<String, u64> {
+    let mut tree: HashMap<String, u64> = HashMap::new();
+
+    let mut path = vec![String::from(".")];
+
+    for line in output {
+        match line {
+            Line::Cd("/") => path.truncate(1),
+            Line::Cd("..") => leave(&mut path),
+            Line::Cd(directory) => path.push(String::from(*directory)),
+            Line::Ls | Line::Dir(_) => {}
+            Line::File(file_size, _) => {
+                for p in all_directories(&path) {
+                    let new_size = tree.get(&p).unwrap_or(&0) + *file_size as u64;
+                    tree.insert(p, new_size);
+                }
+            }
+        }
+    }
+
+    if trace::enabled() {
+        for (directory, size) in tree.iter().sorted() {
+            trace!("directory_sizes", directory = directory, size = size);
+        }
+    }
+
+    return tree;
+}
+
+pub fn part_one(output: &[Line], params: &Params) -> u64 {
+    return directory_sizes(output).values().filter(|v| **v < params.small_directory).sum();
+}
+
+pub fn part_two(output: &[Line], params: &Params) -> Result<u64, Error> {
+    let sizes = directory_sizes(output);
+
+    let missing = space_to_free(*sizes.get(".").unwrap_or(&0), params)?;
+
+    return smallest_to_free(sizes.into_values(), missing);
+}
+
+pub struct Day07;
+
+impl Puzzle for Day07 {
+    type Input<'a> = Vec<Line<'a>>;
+
+    fn day(&self) -> u8 {
+        return 7;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return terminal_output(input);
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        return parse::line_problems(input, terminal_line);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return self.part_one_with(input, &Params::default());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return self.part_two_with(input, &Params::default());
+    }
+}
+
+impl Configure for Day07 {
+    type Params = Params;
+
+    fn part_one_with(&self, input: &Self::Input<'_>, params: &Params) -> Result<Answer, Error> {
+        return Ok(part_one(input, params).into());
+    }
+
+    fn part_two_with(&self, input: &Self::Input<'_>, params: &Params) -> Result<Answer, Error> {
+        return Ok(part_two(input, params)?.into());
+    }
+}
+
+/// Same as [directory_sizes], identifying directories by the index of their parent and their name instead of joining
+/// their paths.
+///
+/// Only directories that contain a file, directly or nested, have a size, just like in [directory_sizes].
+fn directory_sizes_by_index(output: &[Line]) -> Vec<Option<u64>> {
+    let mut directories: HashMap<(Option<usize>, &str), usize> = HashMap::new();
+    let mut sizes: Vec<Option<u64>> = Vec::new();
+
+    let mut path = vec![directory(&mut directories, &mut sizes, None, ".")];
+
+    for line in output {
+        match line {
+            Line::Cd("/") => path.truncate(1),
+            Line::Cd("..") => leave(&mut path),
+            Line::Cd(name) => path.push(directory(&mut directories, &mut sizes, path.last().copied(), name)),
+            Line::Ls | Line::Dir(_) => {}
+            Line::File(file_size, _) => {
+                for index in &path {
+                    sizes[*index] = Some(sizes[*index].unwrap_or(0) + *file_size as u64);
+                }
+            }
+        }
+    }
+
+    return sizes;
+}
+
+/// Index of a directory, adding it if it has not been seen before.
+fn directory<'a>(
+    directories: &mut HashMap<(Option<usize>, &'a str), usize>,
+    sizes: &mut Vec<Option<u64>>,
+    parent: Option<usize>,
+    name: &'a str,
+) -> usize {
+    let next = directories.len();
+    let index = *directories.entry((parent, name)).or_insert(next);
+
+    if index == sizes.len() {
+        sizes.push(None);
+    }
+
+    return index;
+}
+
+impl Stream for Day07 {
+    /// Keeps the size of every directory like [directory_sizes_by_index], with the names of the directories instead of
+    /// the whole terminal output.
+    fn stream(&self, part: u8, input: &mut dyn BufRead) -> Result<Answer, Error> {
+        let mut lines = LineReader::new(input);
+        let mut directories: HashMap<(Option<usize>, String), usize> = HashMap::from([((None, String::from(".")), 0)]);
+        let mut sizes: Vec<Option<u64>> = vec![None];
+        let mut path = vec![0];
+
+        while let Some(line) = lines.next_line()? {
+            match terminal_line(line)? {
+                Line::Cd("/") => path.truncate(1),
+                Line::Cd("..") => leave(&mut path),
+                Line::Cd(name) => {
+                    let next = sizes.len();
+                    let index = *directories.entry((path.last().copied(), String::from(name))).or_insert(next);
+
+                    if index == next {
+                        sizes.push(None);
+                    }
+
+                    path.push(index);
+                }
+                Line::Ls | Line::Dir(_) => {}
+                Line::File(file_size, _) => {
+                    for index in &path {
+                        sizes[*index] = Some(sizes[*index].unwrap_or(0) + file_size as u64);
+                    }
+                }
+            }
+        }
+
+        let params = Params::default();
+
+        if part == 1 {
+            return Ok(sizes.iter().flatten().filter(|size| **size < params.small_directory).sum::<u64>().into());
+        }
+
+        let missing = space_to_free(sizes[0].unwrap_or(0), &params)?;
+
+        return Ok(smallest_to_free(sizes.into_iter().flatten(), missing)?.into());
+    }
+}
+
+/// Same answers as [Day07], without building the path of every directory a file is in.
+pub struct Optimized;
+
+impl Puzzle for Optimized {
+    type Input<'a> = Vec<Line<'a>>;
+
+    fn day(&self) -> u8 {
+        return 7;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return terminal_output(input);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(directory_sizes_by_index(input).iter().flatten().filter(|size| **size < 100000).sum::<u64>().into());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        let sizes = directory_sizes_by_index(input);
+        let missing = space_to_free(sizes[0].unwrap_or(0), &Params::default())?;
+
+        return Ok(smallest_to_free(sizes.into_iter().flatten(), missing)?.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_input() -> String {
+        return String::from(include_str!("../../examples/2022/day07/example.txt"));
+    }
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(94853 + 584, part_one(&terminal_output(&example_input()).unwrap(), &Params::default()));
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(Ok(24933642), part_two(&terminal_output(&example_input()).unwrap(), &Params::default()));
+    }
+
+    #[test]
+    fn directory_sizes_are_traced() {
+        let input = example_input();
+        let output = terminal_output(&input).unwrap();
+        let (_, events) = trace::collect(|| part_one(&output, &Params::default()));
+
+        assert_eq!(
+            vec![(".", "48381165"), ("./a", "94853"), ("./a/e", "584"), ("./d", "24933642")],
+            events.iter().map(|event| (event.field("directory").unwrap(), event.field("size").unwrap())).collect_vec()
+        );
+    }
+
+    #[test]
+    fn part_two_example_with_smaller_disk() {
+        let params = Params { disk_size: 50000000, ..Params::default() };
+
+        assert_eq!(Ok(48381165), part_two(&terminal_output(&example_input()).unwrap(), &params));
+
+        let params = Params { disk_size: 40000000, ..Params::default() };
+
+        assert!(part_two(&terminal_output(&example_input()).unwrap(), &params).is_err());
+    }
+
+    #[test]
+    fn update_larger_than_the_disk() {
+        let params = Params { required_space: 80000000, ..Params::default() };
+
+        assert_eq!(
+            Err(Error::Unsolvable(String::from("No directory frees up the missing space of 58381165"))),
+            part_two(&terminal_output(&example_input()).unwrap(), &params)
+        );
+    }
+
+    #[test]
+    fn invalid_line() {
+        assert_eq!(
+            Some(ParseError::new(3, 1, "a command, a directory or a file size")),
+            terminal_output("$ cd /\n$ ls\nfile a.txt").err()
+        );
+        assert_eq!(Some(ParseError::new(2, 6, "a directory name")), terminal_output("$ cd /\n$ cd ").err());
+    }
+}
@@ -0,0 +1,250 @@
+use itertools::Itertools;
+
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::solution::{Answer, Error, Puzzle};
+use crate::trace::trace;
+use crate::visualize::{Frame, Visualize};
+
+fn visible(values: &[u32], index: usize) -> bool {
+    return index == 0 ||
+        index == values.len() - 1 ||
+        values.iter().take(index).max().unwrap_or(&0) < &values[index] ||
+        values.iter().skip(index + 1).max().unwrap_or(&0) < &values[index];
+}
+
+fn visible_in_row(map: &Grid<u32>, col: usize, row: usize) -> bool {
+    return visible(map.row(row), col);
+}
+
+fn visible_in_col(map: &Grid<u32>, col: usize, row: usize) -> bool {
+    return visible(&map.column(col).copied().collect_vec(), row);
+}
+
+pub fn part_one(map: &Grid<u32>) -> u32 {
+    let mut result = 0;
+
+    for (col, row) in map.positions() {
+        if visible_in_row(map, col, row) || visible_in_col(map, col, row) {
+            trace!("visible", x = col, y = row, height = map[(col, row)]);
+            result += 1;
+        }
+    }
+
+    return result;
+}
+
+fn count(values: Vec<&u32>, limit: u32) -> u32 {
+    let mut count = 0;
+
+    for value in values.iter() {
+        count += 1;
+
+        if *value >= &limit {
+            break;
+        }
+    }
+
+    return count;
+}
+
+fn scenic_score(map: &Grid<u32>, col: usize, row: usize) -> u32 {
+    let line = map.row(row);
+    let column = map.column(col).collect_vec();
+
+    let value = map[(col, row)];
+
+    let score = count(line[0..col].iter().rev().collect_vec(), value) *
+        count(line[col + 1..].iter().collect_vec(), value) *
+        count(column[0..row].iter().rev().copied().collect_vec(), value) *
+        count(column[row + 1..].to_vec(), value);
+    trace!("scenic_score", x = col, y = row, height = value, score = score);
+
+    return score;
+}
+
+pub fn part_two(map: &Grid<u32>) -> u32 {
+    return map.positions().map(|(col, row)| scenic_score(map, col, row)).max().unwrap();
+}
+
+const TREE_HEIGHT: &str = "a tree height 0-9";
+
+fn map_from_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    return Grid::try_parse(input, TREE_HEIGHT, |c| c.to_digit(10));
+}
+
+pub struct Day08;
+
+impl Puzzle for Day08 {
+    type Input<'a> = Grid<u32>;
+
+    fn day(&self) -> u8 {
+        return 8;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return map_from_input(input);
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        return Grid::check(input, TREE_HEIGHT, |c| c.to_digit(10));
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(part_one(input).into());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(part_two(input).into());
+    }
+}
+
+impl Visualize for Day08 {
+    /// The heights of the trees, followed by the trees visible from outside the grid for part one or the tree with the
+    /// highest scenic score, marked as `X`, for part two.
+    fn frames(&self, map: &Grid<u32>, part: u8) -> Vec<Frame> {
+        let heights: Grid<char> = Grid::from_rows(
+            map.rows().map(|row| row.iter().map(|height| char::from_digit(*height, 10).unwrap()).collect()).collect()
+        );
+        let mut picture = heights.clone();
+
+        let caption = if part == 1 {
+            let visible = visible_trees(map);
+
+            for position in map.positions().filter(|position| !visible[*position]) {
+                picture[position] = '.';
+            }
+
+            format!("{} trees visible from outside the grid", map.positions().filter(|p| visible[*p]).count())
+        } else {
+            let best = map.positions().max_by_key(|(col, row)| scenic_score(map, *col, *row)).unwrap();
+            picture[best] = 'X';
+
+            format!("Highest scenic score {}", scenic_score(map, best.0, best.1))
+        };
+
+        return vec![Frame::new("Tree heights", heights.render(|c| *c)), Frame::new(caption, picture.render(|c| *c))];
+    }
+}
+
+/// Trees visible from outside the grid, found by looking along every row and column from both ends while keeping
+/// track of the tallest tree so far, instead of comparing every tree with all trees in its row and column.
+fn visible_trees(map: &Grid<u32>) -> Grid<bool> {
+    let mut visible = Grid::new(map.width(), map.height(), false);
+    let mut lines: Vec<Vec<(usize, usize)>> = Vec::new();
+
+    for row in 0..map.height() {
+        lines.push((0..map.width()).map(|col| (col, row)).collect());
+    }
+
+    for col in 0..map.width() {
+        lines.push((0..map.height()).map(|row| (col, row)).collect());
+    }
+
+    for line in lines {
+        for positions in [line.clone(), line.into_iter().rev().collect()] {
+            let mut tallest = None;
+
+            for position in positions {
+                if tallest.is_none_or(|tallest| tallest < map[position]) {
+                    visible[position] = true;
+                    tallest = Some(map[position]);
+                }
+            }
+        }
+    }
+
+    return visible;
+}
+
+/// Number of trees seen from a position when looking in a direction, indexing the grid instead of copying the row or
+/// column of the tree.
+fn viewing_distance(map: &Grid<u32>, (col, row): (usize, usize), (dx, dy): (isize, isize)) -> u32 {
+    let height = map[(col, row)];
+    let mut position = (col, row);
+    let mut distance = 0;
+
+    while let (Some(x), Some(y)) = (position.0.checked_add_signed(dx), position.1.checked_add_signed(dy)) {
+        if !map.contains((x, y)) {
+            break;
+        }
+
+        position = (x, y);
+        distance += 1;
+
+        if map[position] >= height {
+            break;
+        }
+    }
+
+    return distance;
+}
+
+/// Same answers as [Day08], without copying rows and columns of the grid.
+pub struct Optimized;
+
+impl Puzzle for Optimized {
+    type Input<'a> = Grid<u32>;
+
+    fn day(&self) -> u8 {
+        return 8;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return map_from_input(input);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        let visible = visible_trees(input);
+
+        return Ok(input.positions().filter(|position| visible[*position]).count().into());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(input.positions()
+            .map(|position| {
+                return [(-1, 0), (1, 0), (0, -1), (0, 1)].iter()
+                    .map(|direction| viewing_distance(input, position, *direction))
+                    .product::<u32>();
+            })
+            .max()
+            .unwrap()
+            .into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_input() -> String {
+        return String::from(include_str!("../../examples/2022/day08/example.txt"));
+    }
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(21, part_one(&map_from_input(&example_input()).unwrap()));
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(8, part_two(&map_from_input(&example_input()).unwrap()));
+    }
+
+    #[test]
+    fn frames_of_example() {
+        let map = map_from_input(&example_input()).unwrap();
+
+        assert_eq!(
+            vec![
+                Frame::new("Tree heights", "30373\n25512\n65332\n33549\n35390"),
+                Frame::new("21 trees visible from outside the grid", "30373\n255.2\n65.32\n3.5.9\n35390"),
+            ],
+            Visualize::frames(&Day08, &map, 1)
+        );
+        assert_eq!(
+            Frame::new("Highest scenic score 8", "30373\n25512\n65332\n33X49\n35390"),
+            Visualize::frames(&Day08, &map, 2)[1]
+        );
+    }
+}
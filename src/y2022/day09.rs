@@ -0,0 +1,398 @@
+use std::char::from_digit;
+use std::collections::HashSet;
+use std::io::BufRead;
+use std::ops::Range;
+
+use itertools::Itertools;
+
+use crate::grid::Grid;
+use crate::params::{params, Configure};
+use crate::parse;
+use crate::parse::{Lines, ParseError, Scanner};
+use crate::solution::{Answer, Error, Puzzle};
+use crate::stream::{LineReader, Stream};
+use crate::trace::trace;
+use crate::visualize::{Frame, Visualize};
+
+type Point = (i32, i32);
+
+/// Direction and number of steps the head of the rope moves.
+pub type Motion = (&'static str, u32);
+
+params! {
+    /// Parameters of day 9, see [Configure].
+    pub struct Params {
+        /// Number of knots of the rope in part one
+        short_rope: usize = 2 in 1..,
+        /// Number of knots of the rope in part two
+        long_rope: usize = 10 in 1..,
+    }
+}
+
+pub struct Day09;
+
+impl Puzzle for Day09 {
+    type Input<'a> = Vec<Motion>;
+
+    fn day(&self) -> u8 {
+        return 9;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return motions_from_input(input);
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        return parse::line_problems(input, motion);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return self.part_one_with(input, &Params::default());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return self.part_two_with(input, &Params::default());
+    }
+}
+
+impl Configure for Day09 {
+    type Params = Params;
+
+    fn part_one_with(&self, input: &Self::Input<'_>, params: &Params) -> Result<Answer, Error> {
+        return Ok(part_one(input, params).into());
+    }
+
+    fn part_two_with(&self, input: &Self::Input<'_>, params: &Params) -> Result<Answer, Error> {
+        return Ok(part_two(input, params).into());
+    }
+}
+
+pub fn part_one(motions: &[Motion], params: &Params) -> u32 {
+    return simulate(motions, params.short_rope);
+}
+
+pub fn part_two(motions: &[Motion], params: &Params) -> u32 {
+    return simulate(motions, params.long_rope);
+}
+
+fn motion(mut line: Scanner) -> Result<Motion, ParseError> {
+    let direction = line.one_of(&["R", "L", "U", "D"], "a direction R, L, U or D")?;
+    line.literal(" ")?;
+    let steps = line.number::<u32>("a number of steps")?;
+    line.end()?;
+
+    return Ok((direction, steps));
+}
+
+fn motions_from_input(input: &str) -> Result<Vec<Motion>, ParseError> {
+    return Lines::new(input).map(motion).collect();
+}
+
+fn simulate(motions: &[Motion], rope_length: usize) -> u32 {
+    let mut knots: Vec<Point> = Vec::new();
+    let mut tail_positions: HashSet<Point> = HashSet::new();
+
+    for _ in 0..rope_length {
+        knots.push((0, 0));
+    }
+
+    for (direction, steps) in motions {
+        for _ in 0..*steps {
+            tail_positions.insert(knots[rope_length - 1]);
+
+            move_head(&mut knots, String::from(*direction));
+
+            tail_positions.insert(knots[rope_length - 1]);
+        }
+
+        trace!(
+            "simulate",
+            motion = format!("{direction} {steps}"),
+            head = format!("{:?}", knots[0]),
+            tail = format!("{:?}", knots[rope_length - 1]),
+            visited = tail_positions.len(),
+        );
+    }
+
+    return tail_positions.len() as u32;
+}
+
+fn move_head(knots: &mut [Point], direction: String) {
+    knots[0] = point_moved_in_direction(knots[0], direction);
+
+    for i in 1..knots.len() {
+        knots[i] = position_following(knots[i], knots[i - 1])
+    }
+}
+
+fn point_moved_in_direction(point: Point, direction: String) -> Point {
+    match direction.as_str() {
+        "R" => point_moved_by(point, (1, 0)),
+        "L" => point_moved_by(point, (-1, 0)),
+        "U" => point_moved_by(point, (0, -1)),
+        "D" => point_moved_by(point, (0, 1)),
+        _ => point
+    }
+}
+
+fn point_moved_by(a: Point, b: Point) -> Point {
+    return (a.0 + b.0, a.1 + b.1);
+}
+
+fn position_following(a: Point, b: Point) -> Point {
+    if adjacent_or_overlapping(a, b) {
+        return a;
+    }
+
+    return point_moved_by(a, direction(b, a));
+}
+
+fn adjacent_or_overlapping(a: Point, b: Point) -> bool {
+    return i32::abs(a.0 - b.0) < 2 && i32::abs(a.1 - b.1) < 2;
+}
+
+fn direction(a: Point, b: Point) -> Point {
+    let dx = a.0 - b.0;
+    let dy = a.1 - b.1;
+
+    return (
+        if dx != 0 { dx / i32::abs(dx) } else { 0 },
+        if dy != 0 { dy / i32::abs(dy) } else { 0 }
+    );
+}
+
+/// Knots of a rope and the positions its tail visited, moving the knots by the sign of their distance instead of
+/// matching the direction of every step like [simulate] does.
+struct Rope {
+    knots: Vec<Point>,
+    tail_positions: HashSet<Point>,
+}
+
+impl Rope {
+    fn new(length: usize) -> Rope {
+        return Rope { knots: vec![(0, 0); length], tail_positions: HashSet::new() };
+    }
+
+    fn apply(&mut self, (direction, steps): Motion) {
+        let (dx, dy) = match direction {
+            "R" => (1, 0),
+            "L" => (-1, 0),
+            "U" => (0, -1),
+            _ => (0, 1),
+        };
+
+        for _ in 0..steps {
+            self.knots[0] = (self.knots[0].0 + dx, self.knots[0].1 + dy);
+
+            for i in 1..self.knots.len() {
+                let (x, y) = (self.knots[i - 1].0 - self.knots[i].0, self.knots[i - 1].1 - self.knots[i].1);
+
+                if x.abs() > 1 || y.abs() > 1 {
+                    self.knots[i] = (self.knots[i].0 + x.signum(), self.knots[i].1 + y.signum());
+                }
+            }
+
+            self.tail_positions.insert(*self.knots.last().unwrap());
+        }
+    }
+
+    fn tail_positions(mut self) -> u32 {
+        // Like in [simulate], the starting position only counts once the rope has moved.
+        if !self.tail_positions.is_empty() {
+            self.tail_positions.insert((0, 0));
+        }
+
+        return self.tail_positions.len() as u32;
+    }
+}
+
+/// Same as [simulate], with a [Rope].
+fn simulate_by_sign(motions: &[Motion], rope_length: usize) -> u32 {
+    let mut rope = Rope::new(rope_length);
+
+    for motion in motions {
+        rope.apply(*motion);
+    }
+
+    return rope.tail_positions();
+}
+
+impl Stream for Day09 {
+    /// Keeps the rope and the positions its tail visited.
+    fn stream(&self, part: u8, input: &mut dyn BufRead) -> Result<Answer, Error> {
+        let mut lines = LineReader::new(input);
+        let mut rope = Rope::new(if part == 1 { 2 } else { 10 });
+
+        while let Some(line) = lines.next_line()? {
+            rope.apply(motion(line)?);
+        }
+
+        return Ok(rope.tail_positions().into());
+    }
+}
+
+/// Same answers as [Day09], without allocating a string for every step.
+pub struct Optimized;
+
+impl Puzzle for Optimized {
+    type Input<'a> = Vec<Motion>;
+
+    fn day(&self) -> u8 {
+        return 9;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return motions_from_input(input);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(simulate_by_sign(input, 2).into());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(simulate_by_sign(input, 10).into());
+    }
+}
+
+/// Renders the part of the plane within the ranges, with the head as `H`, the other knots by their number and the
+/// `visited` positions as `#`.
+fn grid_as_string(knots: &[Point], visited: &HashSet<Point>, xr: Range<i32>, yr: Range<i32>) -> String {
+    let mut grid = Grid::new(xr.len(), yr.len(), '.');
+
+    for (x, y) in visited {
+        if xr.contains(x) && yr.contains(y) {
+            grid.set(((x - xr.start) as usize, (y - yr.start) as usize), '#');
+        }
+    }
+
+    for (knot, (x, y)) in knots.iter().enumerate().rev() {
+        if xr.contains(x) && yr.contains(y) {
+            let c = if knot == 0 { 'H' } else { from_digit(knot as u32, 10).unwrap() };
+            grid.set(((x - xr.start) as usize, (y - yr.start) as usize), c);
+        }
+    }
+
+    return grid.render(|c| *c);
+}
+
+/// Largest area shown by the frames of [Day09], larger ropes are followed by a section of this size around the head.
+const VIEWPORT: (i32, i32) = (80, 40);
+
+impl Visualize for Day09 {
+    fn frames(&self, motions: &Vec<Motion>, part: u8) -> Vec<Frame> {
+        let rope_length = if part == 1 { 2 } else { 10 };
+        let mut knots = vec![(0, 0); rope_length];
+        let mut states = vec![(String::from("Start"), knots.clone())];
+
+        for (direction, steps) in motions {
+            for step in 1..=*steps {
+                move_head(&mut knots, String::from(*direction));
+                states.push((format!("{direction} {steps} (step {step} of {steps})"), knots.clone()));
+            }
+        }
+
+        let points = states.iter().flat_map(|(_, knots)| knots.iter());
+        let (min_x, max_x) = points.clone().map(|p| p.0).minmax().into_option().unwrap();
+        let (min_y, max_y) = points.map(|p| p.1).minmax().into_option().unwrap();
+        let fits = max_x - min_x < VIEWPORT.0 && max_y - min_y < VIEWPORT.1;
+
+        let mut visited = HashSet::new();
+
+        return states.into_iter()
+            .map(|(caption, knots)| {
+                visited.insert(knots[rope_length - 1]);
+
+                let (xr, yr) = if fits {
+                    (min_x..max_x + 1, min_y..max_y + 1)
+                } else {
+                    let (x, y) = knots[0];
+                    (x - VIEWPORT.0 / 2..x + VIEWPORT.0 / 2, y - VIEWPORT.1 / 2..y + VIEWPORT.1 / 2)
+                };
+
+                return Frame::new(caption, grid_as_string(&knots, &visited, xr, yr));
+            })
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mutate_test() {
+        let expected = include_str!("../../examples/2022/day09/example.states");
+        let mut actual: Vec<String> = Vec::new();
+
+        let mut data = vec![(0, 0), (0, 0), (0, 0), (0, 0), (0, 0), (0, 0), (0, 0), (0, 0), (0, 0)];
+
+        let instructions = vec![
+            ("R", 4),
+            ("U", 4),
+            ("L", 3),
+            ("D", 1),
+            ("R", 4),
+            ("D", 1),
+            ("L", 5),
+            ("R", 2),
+        ];
+
+        actual.push(grid_as_string(&data, &HashSet::new(), 0..6, -4..1));
+
+        for (direction, steps) in instructions {
+            actual.push(format!("== {direction} {steps} =="));
+
+            for _ in 0..steps {
+                move_head(&mut data, String::from(direction));
+
+                actual.push(grid_as_string(&data, &HashSet::new(), 0..6, -4..1));
+            }
+        }
+
+        assert_eq!(expected, actual.join("\n\n"));
+    }
+
+    fn example_input() -> String {
+        return String::from(include_str!("../../examples/2022/day09/example.txt"));
+    }
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(13, part_one(&motions_from_input(&example_input()).unwrap(), &Params::default()));
+    }
+
+    #[test]
+    fn part_two_example_one() {
+        assert_eq!(1, part_two(&motions_from_input(&example_input()).unwrap(), &Params::default()));
+    }
+
+    #[test]
+    fn part_two_example_two() {
+        let input = include_str!("../../examples/2022/day09/larger.txt");
+
+        assert_eq!(36, part_two(&motions_from_input(input).unwrap(), &Params::default()));
+    }
+
+    #[test]
+    fn single_knot_visits_every_position_of_the_head() {
+        let params = Params { short_rope: 1, ..Params::default() };
+
+        assert_eq!(4, part_one(&motions_from_input("R 2\nU 1\n").unwrap(), &params));
+    }
+
+    #[test]
+    fn invalid_motion() {
+        assert_eq!(Err(ParseError::new(2, 1, "a direction R, L, U or D")), motions_from_input("R 4\nX 4"));
+        assert_eq!(Err(ParseError::new(1, 3, "a number of steps")), motions_from_input("R four"));
+    }
+
+    #[test]
+    fn frames_of_example() {
+        let frames = Visualize::frames(&Day09, &motions_from_input(&example_input()).unwrap(), 1);
+
+        assert_eq!(25, frames.len());
+        assert_eq!(Frame::new("Start", "......\n......\n......\n......\nH....."), frames[0]);
+        assert_eq!(Frame::new("R 4 (step 2 of 4)", "......\n......\n......\n......\n#1H..."), frames[2]);
+        assert_eq!(Frame::new("R 2 (step 2 of 2)", "..##..\n...##.\n.1H##.\n....#.\n####.."), frames[24]);
+    }
+}
@@ -0,0 +1,389 @@
+use std::io::BufRead;
+
+use crate::grid::Grid;
+use crate::params::{params, Configure};
+use crate::parse;
+use crate::parse::{Lines, ParseError, Scanner};
+use crate::solution::{Answer, Error, Puzzle};
+use crate::stream::{LineReader, Stream};
+use crate::trace::trace;
+use crate::visualize::{Frame, Visualize};
+
+params! {
+    /// Parameters of day 10, see [Configure].
+    pub struct Params {
+        /// Number of pixels per row of the screen, which also decides the cycles counted in part one
+        width: usize = 40 in 1..=1000,
+        /// Number of rows of the screen
+        height: usize = 6 in 1..=1000,
+    }
+}
+
+impl Params {
+    /// Number of cycles it takes to draw the screen.
+    fn cycles(&self) -> usize {
+        return self.width * self.height;
+    }
+
+    /// Whether the signal strength during a cycle, counted from zero, counts: the cycles that would draw the middle of
+    /// a row, which are the 20th, 60th, 100th and so on for as long as the program runs on a screen 40 pixels wide.
+    fn is_signal_cycle(&self, cycle: usize) -> bool {
+        return (cycle + 1) % self.width == self.width / 2 % self.width;
+    }
+}
+
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+fn instruction(mut line: Scanner) -> Result<Instruction, ParseError> {
+    let instruction = match line.one_of(&["noop", "addx "], "an instruction noop or addx")? {
+        "addx " => Instruction::Addx(line.number::<i32>("a number")?),
+        _ => Instruction::Noop,
+    };
+    line.end()?;
+
+    return Ok(instruction);
+}
+
+fn instructions_from_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    return Lines::new(input).map(instruction).collect();
+}
+
+/// Value of the register during every cycle, as `i64` since adding up `i32` values may exceed their range.
+fn cycle_values(instructions: &[Instruction]) -> Vec<i64> {
+    let mut x = 1;
+    let mut cycle_values: Vec<i64> = Vec::new();
+
+    for instruction in instructions {
+        cycle_values.push(x);
+
+        match instruction {
+            Instruction::Noop => trace!("cycle_values", end_of_cycle = cycle_values.len(), instruction = "noop", x = x),
+            Instruction::Addx(value) => {
+                cycle_values.push(x);
+
+                x += *value as i64;
+                trace!("cycle_values", end_of_cycle = cycle_values.len(), instruction = format!("addx {value}"), x = x);
+            }
+        }
+    }
+
+    cycle_values.push(x);
+
+    return cycle_values;
+}
+
+/// Adds the signal strength during a cycle, counted from zero, to `sum` if it is one of the cycles that count, see
+/// [Params::is_signal_cycle].
+///
+/// `None` once the sum is out of range.
+fn add_signal_strength(sum: Option<i64>, cycle: usize, x: i64, params: &Params) -> Option<i64> {
+    if !params.is_signal_cycle(cycle) {
+        return sum;
+    }
+
+    let signal_strength = (cycle as i64 + 1).checked_mul(x)?;
+    trace!("add_signal_strength", cycle = cycle + 1, x = x, signal_strength = signal_strength);
+
+    return sum?.checked_add(signal_strength);
+}
+
+fn signal_strength_sum(sum: Option<i64>) -> Result<i64, Error> {
+    return sum.ok_or_else(|| Error::Unsolvable(String::from("The sum of the signal strengths is out of range")));
+}
+
+pub fn part_one(instructions: &[Instruction], params: &Params) -> Result<i64, Error> {
+    let sum = cycle_values(instructions).iter()
+        .enumerate()
+        .try_fold(0, |sum, (cycle, x)| add_signal_strength(Some(sum), cycle, *x, params));
+
+    return signal_strength_sum(sum);
+}
+
+/// Lights the pixel drawn during a cycle, counted from zero, if the sprite is on it. Cycles after the last pixel draw
+/// nothing.
+fn draw(screen: &mut Grid<bool>, cycle: usize, x: i64, params: &Params) {
+    if cycle < params.cycles() {
+        let column = cycle % params.width;
+        screen.set((column, cycle / params.width), i64::abs(x - column as i64) <= 1);
+    }
+}
+
+fn render(screen: &Grid<bool>) -> String {
+    return screen.render(|lit| if *lit { '#' } else { '.' }) + "\n";
+}
+
+pub fn part_two(instructions: &[Instruction], params: &Params) -> String {
+    let mut screen = Grid::new(params.width, params.height, false);
+
+    for (cycle, x) in cycle_values(instructions).iter().enumerate() {
+        draw(&mut screen, cycle, *x, params);
+    }
+
+    return render(&screen);
+}
+
+pub struct Day10;
+
+impl Puzzle for Day10 {
+    type Input<'a> = Vec<Instruction>;
+
+    fn day(&self) -> u8 {
+        return 10;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return instructions_from_input(input);
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        return parse::line_problems(input, instruction);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return self.part_one_with(input, &Params::default());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return self.part_two_with(input, &Params::default());
+    }
+}
+
+impl Configure for Day10 {
+    type Params = Params;
+
+    fn part_one_with(&self, input: &Self::Input<'_>, params: &Params) -> Result<Answer, Error> {
+        return Ok(Answer::Number(part_one(input, params)?));
+    }
+
+    fn part_two_with(&self, input: &Self::Input<'_>, params: &Params) -> Result<Answer, Error> {
+        return Ok(part_two(input, params).into());
+    }
+}
+
+impl Visualize for Day10 {
+    /// A frame per cycle with the pixels drawn so far and the position of the sprite below the screen. Part one adds
+    /// the sum of the signal strengths to the caption. The screen has the default size, since `viz` takes no
+    /// `--param`.
+    fn frames(&self, instructions: &Vec<Instruction>, part: u8) -> Vec<Frame> {
+        let params = Params::default();
+        let mut screen = Grid::new(params.width, params.height, '.');
+        let mut signal_strength = 0;
+
+        return cycle_values(instructions).iter()
+            .take(params.cycles())
+            .enumerate()
+            .map(|(cycle, x)| {
+                let column = cycle % params.width;
+
+                if (x - column as i64).abs() <= 1 {
+                    screen.set((column, cycle / params.width), '#');
+                }
+
+                let sprite: String = (0..params.width as i64)
+                    .map(|c| if (x - c).abs() <= 1 { '#' } else { ' ' })
+                    .collect();
+                let mut caption = format!("Cycle {}: X = {x}", cycle + 1);
+
+                if part == 1 {
+                    if params.is_signal_cycle(cycle) {
+                        signal_strength += (cycle + 1) as i64 * x;
+                    }
+
+                    caption += &format!(", signal strength {signal_strength}");
+                }
+
+                return Frame::new(caption, format!("{}\n{}", screen.render(|c| *c), sprite.trim_end()));
+            })
+            .collect();
+    }
+}
+
+/// Same as [cycle_values], passing the cycle, counted from zero, and the value of the register to `callback` instead
+/// of collecting the values.
+fn each_cycle(instructions: &[Instruction], mut callback: impl FnMut(usize, i64)) {
+    let mut cpu = Cpu { cycle: 0, x: 1 };
+
+    for instruction in instructions {
+        cpu.execute(instruction, &mut callback);
+    }
+
+    callback(cpu.cycle, cpu.x);
+}
+
+/// Clock and register of the CPU, executing one instruction at a time.
+struct Cpu {
+    cycle: usize,
+    x: i64,
+}
+
+impl Cpu {
+    /// Passes every cycle the instruction takes and the value of the register during that cycle to `callback`.
+    fn execute(&mut self, instruction: &Instruction, callback: &mut impl FnMut(usize, i64)) {
+        callback(self.cycle, self.x);
+        self.cycle += 1;
+
+        if let Instruction::Addx(value) = instruction {
+            callback(self.cycle, self.x);
+            self.cycle += 1;
+
+            self.x += *value as i64;
+        }
+    }
+}
+
+impl Stream for Day10 {
+    /// Executes the instructions as they are read, keeping the sum of the signal strengths and the screen. The
+    /// parameters are the default ones, since `--stream` conflicts with `--param`.
+    fn stream(&self, part: u8, input: &mut dyn BufRead) -> Result<Answer, Error> {
+        let mut lines = LineReader::new(input);
+        let mut cpu = Cpu { cycle: 0, x: 1 };
+        let params = Params::default();
+        let mut sum = Some(0);
+        let mut screen = Grid::new(params.width, params.height, false);
+
+        let mut observe = |cycle: usize, x: i64| {
+            sum = add_signal_strength(sum, cycle, x, &params);
+            draw(&mut screen, cycle, x, &params);
+        };
+
+        while let Some(line) = lines.next_line()? {
+            cpu.execute(&instruction(line)?, &mut observe);
+        }
+
+        observe(cpu.cycle, cpu.x);
+
+        if part == 1 {
+            return Ok(Answer::Number(signal_strength_sum(sum)?));
+        }
+
+        return Ok(render(&screen).into());
+    }
+}
+
+/// Same answers as [Day10], without collecting the value of the register during every cycle.
+pub struct Optimized;
+
+impl Puzzle for Optimized {
+    type Input<'a> = Vec<Instruction>;
+
+    fn day(&self) -> u8 {
+        return 10;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return instructions_from_input(input);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        let params = Params::default();
+        let mut sum = Some(0);
+
+        each_cycle(input, |cycle, x| sum = add_signal_strength(sum, cycle, x, &params));
+
+        return Ok(Answer::Number(signal_strength_sum(sum)?));
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        let params = Params::default();
+        let mut screen = Grid::new(params.width, params.height, false);
+
+        each_cycle(input, |cycle, x| draw(&mut screen, cycle, x, &params));
+
+        return Ok(render(&screen).into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_input() -> String {
+        return String::from(include_str!("../../examples/2022/day10/example.txt"));
+    }
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&instructions_from_input(&example_input()).unwrap(), &Params::default()), Ok(13140));
+    }
+
+    #[test]
+    fn part_one_longer_than_the_screen() {
+        // The 260th cycle counts too, during which X is 17
+        let input = example_input().trim_end().to_owned() + &"\nnoop".repeat(20);
+        let instructions = instructions_from_input(&input).unwrap();
+
+        assert_eq!(Ok(13140 + 260 * 17), part_one(&instructions, &Params::default()));
+    }
+
+    #[test]
+    fn part_one_example_on_narrower_screen() {
+        let instructions = instructions_from_input(&example_input()).unwrap();
+        // The 10th, 30th, ... cycle and the value of X during it
+        let signal_cycles = [
+            (10, 8), (30, 28), (50, 24), (70, 31), (90, 9), (110, 13),
+            (130, 11), (150, 31), (170, 1), (190, 28), (210, -1), (230, 30),
+        ];
+        let expected = signal_cycles.iter().map(|(cycle, x)| cycle * x).sum();
+
+        assert_eq!(Ok(expected), part_one(&instructions, &Params { width: 20, height: 3 }));
+    }
+
+    #[test]
+    fn cycle_values_are_traced() {
+        let instructions = instructions_from_input("noop\naddx 3\naddx -5\n").unwrap();
+        let (_, events) = crate::trace::collect(|| cycle_values(&instructions));
+
+        assert_eq!(
+            vec!["1 noop 1", "3 addx 3 4", "5 addx -5 -1"],
+            events.iter()
+                .map(|event| event.fields.iter().map(|(_, value)| value.as_str()).collect::<Vec<&str>>().join(" "))
+                .collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(
+            part_two(&instructions_from_input(&example_input()).unwrap(), &Params::default()),
+            "##..##..##..##..##..##..##..##..##..##..\n".to_owned() +
+                "###...###...###...###...###...###...###.\n" +
+                "####....####....####....####....####....\n" +
+                "#####.....#####.....#####.....#####.....\n" +
+                "######......######......######......####\n" +
+                "#######.......#######.......#######.....\n"
+        );
+    }
+
+    #[test]
+    fn part_two_example_on_smaller_screen() {
+        assert_eq!(
+            "##..##..##..##..##..\n".to_owned() +
+                "....................\n",
+            part_two(&instructions_from_input(&example_input()).unwrap(), &Params { width: 20, height: 2 })
+        );
+    }
+
+    #[test]
+    fn invalid_instruction() {
+        assert_eq!(
+            Some(ParseError::new(2, 1, "an instruction noop or addx")),
+            instructions_from_input("noop\nsubx 3").err()
+        );
+        assert_eq!(Some(ParseError::new(1, 6, "a number")), instructions_from_input("addx x").err());
+    }
+
+    #[test]
+    fn frames_of_example() {
+        let frames = Visualize::frames(&Day10, &instructions_from_input(&example_input()).unwrap(), 1);
+
+        assert_eq!(240, frames.len());
+        assert_eq!("Cycle 1: X = 1, signal strength 0", frames[0].caption);
+        assert_eq!("Cycle 20: X = 21, signal strength 420", frames[19].caption);
+        assert_eq!("Cycle 240: X = 17, signal strength 13140", frames[239].caption);
+        assert!(frames[1].picture.starts_with("##......"));
+        assert!(frames[1].picture.ends_with("\n###"));
+    }
+}
@@ -0,0 +1,500 @@
+use std::cell::{Cell, RefCell};
+
+use itertools::Itertools;
+
+use crate::params::{params, Configure};
+use crate::parse::{Lines, ParseError, Scanner};
+use crate::solution::{Answer, Error, Puzzle};
+use crate::trace::trace;
+
+params! {
+    /// Parameters of day 11, see [Configure].
+    pub struct Params {
+        /// Number of rounds of part one
+        rounds_one: u64 = 20 in 1..=100000,
+        /// Number of rounds of part two
+        rounds_two: u64 = 10000 in 1..=100000,
+        /// Divisor of the worry level after every inspection in part one
+        relief: u64 = 3 in 1..,
+    }
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: RefCell<Vec<u64>>,
+    operation: String,
+    divisible: u64,
+    divisible_true: usize,
+    divisible_false: usize,
+    inspections: Cell<u64>,
+}
+
+impl Monkey {
+    fn next_monkey(&self, worry_level: u64) -> usize {
+        if worry_level.is_multiple_of(self.divisible) { self.divisible_true } else { self.divisible_false }
+    }
+
+    fn worry_level(&self, worry_level: &u64) -> Result<u64, String> {
+        let operation = self.operation.replace("old", worry_level.to_string().as_str());
+        let mut iter = operation.splitn(3, " ");
+        let left = iter.next().unwrap().parse::<u64>().unwrap();
+        let operator = iter.next().unwrap();
+        let right = iter.next().unwrap().parse::<u64>().unwrap();
+
+        let new_worry_level = match operator {
+            "+" => left.checked_add(right),
+            "*" => left.checked_mul(right),
+            _ => panic!("unexpected operator {operator}")
+        };
+
+        return new_worry_level.ok_or_else(|| worry_level_too_large(&operation));
+    }
+
+    fn increment_inspections(&self) {
+        self.inspections.set(self.inspections.get() + 1);
+    }
+
+    fn drain_items(&self) -> Vec<u64> {
+        return self.items.take();
+    }
+
+    fn add_item(&self, worry_level: &u64) {
+        self.items.borrow_mut().push(*worry_level);
+    }
+}
+
+fn worry_level_too_large(operation: &str) -> String {
+    return format!("The worry level of {operation} is too large");
+}
+
+/// Product of the two largest inspection counts.
+fn monkey_business(inspections: impl Iterator<Item = u64>) -> Result<u64, String> {
+    return inspections.sorted().rev().take(2)
+        .try_fold(1u64, |product, count| product.checked_mul(count))
+        .ok_or_else(|| String::from("The level of monkey business is too large"));
+}
+
+/// Product of the divisors of all monkeys, which keeps the worry levels small without changing where items go.
+fn divisor_product(monkeys: &[Monkey]) -> Result<u64, String> {
+    return monkeys.iter()
+        .try_fold(1u64, |product, monkey| product.checked_mul(monkey.divisible))
+        .ok_or_else(|| String::from("The product of the divisors is too large"));
+}
+
+fn operand(line: &mut Scanner) -> Result<String, ParseError> {
+    if line.accept("old") {
+        return Ok(String::from("old"));
+    }
+
+    return Ok(line.number::<u64>("`old` or a number")?.to_string());
+}
+
+fn throw_target(line: &mut Scanner, targets: &mut Vec<(usize, usize, usize)>) -> Result<usize, ParseError> {
+    let column = line.column();
+    let target = line.number("a monkey number")?;
+    line.end()?;
+
+    targets.push((target, line.line(), column));
+
+    return Ok(target);
+}
+
+/// Parses the block of the monkey with the given index, recording where its throw targets are.
+fn monkey(lines: &mut Lines, index: usize, targets: &mut Vec<(usize, usize, usize)>) -> Result<Monkey, ParseError> {
+    let mut line = lines.expect("a monkey")?;
+    line.literal("Monkey ")?;
+    line.literal(&index.to_string())?;
+    line.literal(":")?;
+    line.end()?;
+
+    let mut line = lines.expect("the starting items")?;
+    line.literal("  Starting items: ")?;
+    let mut items = vec![line.number("a worry level")?];
+    while line.accept(", ") {
+        items.push(line.number("a worry level")?);
+    }
+    line.end()?;
+
+    let mut line = lines.expect("an operation")?;
+    line.literal("  Operation: new = ")?;
+    let left = operand(&mut line)?;
+    let operator = line.one_of(&[" + ", " * "], "an operator + or *")?;
+    let right = operand(&mut line)?;
+    line.end()?;
+
+    let mut line = lines.expect("a test")?;
+    line.literal("  Test: divisible by ")?;
+    let column = line.column();
+    let divisible = line.number("a divisor")?;
+    if divisible == 0 {
+        return Err(ParseError::new(line.line(), column, "a divisor greater than 0"));
+    }
+    line.end()?;
+
+    let mut line = lines.expect("a target if true")?;
+    line.literal("    If true: throw to monkey ")?;
+    let divisible_true = throw_target(&mut line, targets)?;
+
+    let mut line = lines.expect("a target if false")?;
+    line.literal("    If false: throw to monkey ")?;
+    let divisible_false = throw_target(&mut line, targets)?;
+
+    return Ok(Monkey {
+        items: RefCell::new(items),
+        operation: format!("{left}{operator}{right}"),
+        divisible,
+        divisible_true,
+        divisible_false,
+        inspections: Cell::new(0),
+    });
+}
+
+/// Whether another monkey follows the one just parsed, failing if the line after it is not empty.
+fn another_monkey(lines: &mut Lines) -> Result<bool, ParseError> {
+    return match lines.next() {
+        None => Ok(false),
+        Some(line) if line.is_at_end() => Ok(true),
+        Some(line) => Err(line.error("an empty line")),
+    };
+}
+
+/// Problems of the throw targets that do not refer to one of the monkeys.
+fn unknown_targets(targets: &[(usize, usize, usize)], monkeys: usize) -> impl Iterator<Item = ParseError> + '_ {
+    let expected = format!("a monkey between 0 and {}", monkeys.saturating_sub(1));
+
+    return targets.iter()
+        .filter(move |(target, _, _)| *target >= monkeys)
+        .map(move |(_, line, column)| ParseError::new(*line, *column, &expected));
+}
+
+fn monkeys_from_input(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut lines = Lines::new(input);
+    let mut monkeys = Vec::new();
+    let mut targets = Vec::new();
+
+    loop {
+        monkeys.push(monkey(&mut lines, monkeys.len(), &mut targets)?);
+
+        if !another_monkey(&mut lines)? {
+            break;
+        }
+    }
+
+    if let Some(problem) = unknown_targets(&targets, monkeys.len()).next() {
+        return Err(problem);
+    }
+
+    return Ok(monkeys);
+}
+
+/// Every problem of the monkeys. After a problem the rest of the block of the monkey is skipped, the next block is
+/// expected to describe the next monkey.
+fn monkey_problems(input: &str) -> Vec<ParseError> {
+    let mut lines = Lines::new(input);
+    let mut problems = Vec::new();
+    let mut targets = Vec::new();
+    let mut monkeys = 0;
+
+    loop {
+        let result = monkey(&mut lines, monkeys, &mut targets).and_then(|_| another_monkey(&mut lines));
+        monkeys += 1;
+
+        let problem = match result {
+            Ok(true) => continue,
+            Ok(false) => break,
+            Err(problem) => problem,
+        };
+
+        // A block ending early runs into the empty line before the next one, which must not be skipped then
+        let at_separator = input.lines().nth(problem.line - 1).is_some_and(str::is_empty);
+        problems.push(problem);
+
+        if !at_separator && !lines.any(|line| line.is_at_end()) {
+            break;
+        }
+    }
+
+    problems.extend(unknown_targets(&targets, monkeys));
+
+    return problems;
+}
+
+fn inspections(monkeys: &mut [Monkey], rounds: u64, divisor: u64, divprod: u64) -> Result<u64, String> {
+    for round in 1..=rounds {
+        for index in 0..monkeys.len() {
+            let monkey = monkeys.get(index).unwrap();
+
+            for current_worry_level in monkey.drain_items() {
+                monkey.increment_inspections();
+
+                let mut new_worry_level = monkey.worry_level(&current_worry_level)? / divisor;
+
+                if divprod != 0 {
+                    new_worry_level %= divprod;
+                }
+
+                let target = monkey.next_monkey(new_worry_level);
+                trace!(
+                    "inspections",
+                    round = round,
+                    monkey = index,
+                    worry_level = current_worry_level,
+                    new_worry_level = new_worry_level,
+                    target = target,
+                );
+
+                monkeys[target].add_item(&new_worry_level);
+            }
+        }
+    }
+
+    return monkey_business(monkeys.iter().map(|m| m.inspections.get()));
+}
+
+pub fn part_one(monkeys: &[Monkey], params: &Params) -> Result<u64, Error> {
+    return inspections(&mut monkeys.to_vec(), params.rounds_one, params.relief, 0).map_err(Error::Unsolvable);
+}
+
+pub fn part_two(monkeys: &[Monkey], params: &Params) -> Result<u64, Error> {
+    let divprod = divisor_product(monkeys).map_err(Error::Unsolvable)?;
+
+    return inspections(&mut monkeys.to_vec(), params.rounds_two, 1, divprod).map_err(Error::Unsolvable);
+}
+
+pub struct Day11;
+
+impl Puzzle for Day11 {
+    type Input<'a> = Vec<Monkey>;
+
+    fn day(&self) -> u8 {
+        return 11;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return monkeys_from_input(input);
+    }
+
+    fn check(&self, input: &str) -> Vec<ParseError> {
+        return monkey_problems(input);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return self.part_one_with(input, &Params::default());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return self.part_two_with(input, &Params::default());
+    }
+}
+
+impl Configure for Day11 {
+    type Params = Params;
+
+    fn part_one_with(&self, input: &Self::Input<'_>, params: &Params) -> Result<Answer, Error> {
+        return Ok(part_one(input, params)?.into());
+    }
+
+    fn part_two_with(&self, input: &Self::Input<'_>, params: &Params) -> Result<Answer, Error> {
+        return Ok(part_two(input, params)?.into());
+    }
+}
+
+enum Operand {
+    Old,
+    Number(u64),
+}
+
+impl Operand {
+    fn from_str(operand: &str) -> Operand {
+        return match operand {
+            "old" => Operand::Old,
+            number => Operand::Number(number.parse().unwrap()),
+        };
+    }
+
+    fn value(&self, old: u64) -> u64 {
+        return match self {
+            Operand::Old => old,
+            Operand::Number(number) => *number,
+        };
+    }
+}
+
+/// The operation of a monkey, parsed once instead of for every item the monkey inspects.
+struct Operation {
+    left: Operand,
+    multiply: bool,
+    right: Operand,
+}
+
+impl Operation {
+    fn from_str(operation: &str) -> Operation {
+        let mut iter = operation.splitn(3, " ");
+        let left = Operand::from_str(iter.next().unwrap());
+        let multiply = iter.next().unwrap() == "*";
+        let right = Operand::from_str(iter.next().unwrap());
+
+        return Operation { left, multiply, right };
+    }
+
+    fn apply(&self, old: u64) -> Result<u64, String> {
+        let (left, right) = (self.left.value(old), self.right.value(old));
+        let new = if self.multiply { left.checked_mul(right) } else { left.checked_add(right) };
+
+        return new.ok_or_else(|| {
+            worry_level_too_large(&format!("{left}{}{right}", if self.multiply { " * " } else { " + " }))
+        });
+    }
+}
+
+/// Same as [inspections], with the operations parsed up front and the items in plain vectors.
+fn inspections_with_parsed_operations(
+    monkeys: &[Monkey],
+    rounds: u64,
+    divisor: u64,
+    divprod: u64,
+) -> Result<u64, String> {
+    let operations: Vec<Operation> = monkeys.iter().map(|m| Operation::from_str(&m.operation)).collect();
+    let mut items: Vec<Vec<u64>> = monkeys.iter().map(|m| m.items.borrow().clone()).collect();
+    let mut inspections = vec![0; monkeys.len()];
+
+    for _ in 0..rounds {
+        for index in 0..monkeys.len() {
+            let monkey = &monkeys[index];
+            inspections[index] += items[index].len() as u64;
+
+            for current_worry_level in std::mem::take(&mut items[index]) {
+                let mut new_worry_level = operations[index].apply(current_worry_level)? / divisor;
+
+                if divprod != 0 {
+                    new_worry_level %= divprod;
+                }
+
+                items[monkey.next_monkey(new_worry_level)].push(new_worry_level);
+            }
+        }
+    }
+
+    return monkey_business(inspections.into_iter());
+}
+
+/// Same answers as [Day11], without turning the worry level into a string and back for every inspection.
+pub struct Optimized;
+
+impl Puzzle for Optimized {
+    type Input<'a> = Vec<Monkey>;
+
+    fn day(&self) -> u8 {
+        return 11;
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        return monkeys_from_input(input);
+    }
+
+    fn part_one(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        return Ok(inspections_with_parsed_operations(input, 20, 3, 0).map_err(Error::Unsolvable)?.into());
+    }
+
+    fn part_two(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        let divprod = divisor_product(input).map_err(Error::Unsolvable)?;
+
+        return Ok(inspections_with_parsed_operations(input, 10_000, 1, divprod).map_err(Error::Unsolvable)?.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::Solution;
+
+    use super::*;
+
+    fn example_input() -> String {
+        return String::from(include_str!("../../examples/2022/day11/example.txt"));
+    }
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&monkeys_from_input(&example_input()).unwrap(), &Params::default()), Ok(10605));
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&monkeys_from_input(&example_input()).unwrap(), &Params::default()), Ok(2713310158));
+    }
+
+    #[test]
+    fn inspections_are_traced() {
+        let monkeys = monkeys_from_input(&example_input()).unwrap();
+        let (_, events) = crate::trace::collect(|| part_one(&monkeys, &Params::default()));
+
+        assert_eq!(101 + 95 + 7 + 105, events.len());
+        assert_eq!(
+            "inspections round=1 monkey=0 worry_level=79 new_worry_level=500 target=3",
+            events[0].to_string()
+        );
+    }
+
+    #[test]
+    fn part_two_example_with_fewer_rounds() {
+        let monkeys = monkeys_from_input(&example_input()).unwrap();
+
+        assert_eq!(Ok(99 * 103), part_two(&monkeys, &Params { rounds_two: 20, ..Params::default() }));
+        assert_eq!(Ok(4 * 6), part_two(&monkeys, &Params { rounds_two: 1, ..Params::default() }));
+    }
+
+    #[test]
+    fn divisor_of_zero() {
+        let input = example_input().replace("divisible by 23", "divisible by 0");
+
+        assert_eq!(Some(ParseError::new(4, 22, "a divisor greater than 0")), monkeys_from_input(&input).err());
+    }
+
+    #[test]
+    fn worry_level_overflow() {
+        let input = example_input().replace("old * 19", "old * 18446744073709551615");
+
+        assert_eq!(
+            Err(Error::Unsolvable(String::from("The worry level of 79 * 18446744073709551615 is too large"))),
+            part_one(&monkeys_from_input(&input).unwrap(), &Params::default())
+        );
+    }
+
+    #[test]
+    fn every_problem_is_found() {
+        let input = example_input()
+            .replacen("    If false: throw to monkey 3\n", "", 1)
+            .replace("new = old + 6", "new = old - 6")
+            .replace("If true: throw to monkey 1", "If true: throw to monkey 9");
+
+        assert_eq!(
+            vec![
+                ParseError::new(6, 1, "`    If false: throw to monkey `"),
+                ParseError::new(9, 23, "an operator + or *"),
+                ParseError::new(18, 30, "a monkey between 0 and 3"),
+            ],
+            Solution::check(&Day11, &input)
+        );
+    }
+
+    #[test]
+    fn invalid_operation() {
+        let input = example_input().replace("new = old + 6", "new = old - 6");
+
+        assert_eq!(Some(ParseError::new(10, 23, "an operator + or *")), monkeys_from_input(&input).err());
+    }
+
+    #[test]
+    fn throw_to_unknown_monkey() {
+        let input = example_input().replace("If false: throw to monkey 3", "If false: throw to monkey 4");
+
+        assert_eq!(Some(ParseError::new(6, 31, "a monkey between 0 and 3")), monkeys_from_input(&input).err());
+    }
+
+    #[test]
+    fn truncated_input() {
+        let input = example_input().lines().take(11).join("\n");
+
+        assert_eq!(Some(ParseError::new(11, 24, "a target if true")), monkeys_from_input(&input).err());
+    }
+}
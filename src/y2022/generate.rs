@@ -0,0 +1,306 @@
+use itertools::Itertools;
+
+use crate::generate::{letters, Generator, Rng};
+
+/// Generator of random but valid inputs of a day, used to compare implementations and to stress test them.
+pub fn generator(day: u8) -> Option<Generator> {
+    let generator: Generator = match day {
+        1 => calories,
+        2 => strategy_guide,
+        3 => rucksacks,
+        4 => section_pairs,
+        5 => crate_stacks,
+        6 => datastream,
+        7 => terminal_output,
+        8 => tree_grid,
+        9 => rope_motions,
+        10 => cpu_program,
+        11 => monkeys,
+        _ => return None,
+    };
+
+    return Some(generator);
+}
+
+/// A size of a day that is realistic or larger, which is where overflows show up. Sizes mean lines, items or cells
+/// depending on the day, so each day has one of its own.
+pub fn large_size(day: u8) -> Option<usize> {
+    let size = match day {
+        1 | 2 | 4 | 6 | 10 => 20000,
+        3 => 10000,
+        7 => 5000,
+        5 | 9 => 2000,
+        8 | 11 => 100,
+        _ => return None,
+    };
+
+    return Some(size);
+}
+
+const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+const ITEM_TYPES: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// `size` elves carrying 1 to 6 items each.
+fn calories(rng: &mut Rng, size: usize) -> String {
+    return (0..size.max(1))
+        .map(|_| (0..rng.between(1, 6)).map(|_| rng.between(1000, 60000)).join("\n"))
+        .join("\n\n");
+}
+
+/// `size` rounds.
+fn strategy_guide(rng: &mut Rng, size: usize) -> String {
+    return (0..size.max(1))
+        .map(|_| format!("{} {}", rng.pick(&["A", "B", "C"]), rng.pick(&["X", "Y", "Z"])))
+        .join("\n");
+}
+
+/// `size` rucksacks rounded up to whole groups of three, each with an item type in both compartments and each group
+/// with an item type in all three rucksacks.
+fn rucksacks(rng: &mut Rng, size: usize) -> String {
+    let mut lines = Vec::new();
+
+    for _ in 0..size.div_ceil(3).max(1) {
+        let badge = *rng.pick(ITEM_TYPES) as char;
+
+        for _ in 0..3 {
+            let shared = *rng.pick(ITEM_TYPES) as char;
+            let len = rng.between(0, 15) as usize;
+
+            let mut first = letters(rng, ITEM_TYPES, len) + &badge.to_string();
+            let mut second = letters(rng, ITEM_TYPES, len + 1);
+            first.insert(rng.index(first.len() + 1), shared);
+            second.insert(rng.index(second.len() + 1), shared);
+
+            lines.push(first + &second);
+        }
+    }
+
+    return lines.join("\n");
+}
+
+/// `size` pairs of section ranges.
+fn section_pairs(rng: &mut Rng, size: usize) -> String {
+    let mut range = || {
+        let start = rng.between(1, 99);
+        return format!("{start}-{}", rng.between(start, 99));
+    };
+
+    return (0..size.max(1)).map(|_| format!("{},{}", range(), range())).join("\n");
+}
+
+/// Two to nine stacks with up to `size` crates each, followed by `size` moves that never empty a stack.
+fn crate_stacks(rng: &mut Rng, size: usize) -> String {
+    let count = rng.between(2, 9) as usize;
+    let mut stacks: Vec<String> = (0..count)
+        .map(|_| {
+            let height = rng.between(1, size.max(1) as i64) as usize;
+            return letters(rng, b"ABCDEFGHIJKLMNOPQRSTUVWXYZ", height);
+        })
+        .collect();
+
+    // A stack with two crates makes sure there always is a stack to move crates from without emptying it
+    if stacks.iter().all(|stack| stack.len() < 2) {
+        stacks[0].push('A');
+    }
+
+    let height = stacks.iter().map(String::len).max().unwrap();
+    let mut lines = Vec::new();
+
+    for level in (0..height).rev() {
+        let line = stacks.iter()
+            .map(|stack| match stack.as_bytes().get(level) {
+                Some(item) => format!("[{}]", *item as char),
+                None => String::from("   "),
+            })
+            .join(" ");
+
+        lines.push(String::from(line.trim_end()));
+    }
+
+    lines.push((1..=count).map(|key| format!(" {key} ")).join(" ").trim_end().to_string());
+    lines.push(String::new());
+
+    let mut heights: Vec<usize> = stacks.iter().map(String::len).collect();
+
+    for _ in 0..size.max(1) {
+        let sources: Vec<usize> = (0..count).filter(|i| heights[*i] > 1).collect();
+        let from = *rng.pick(&sources);
+        let to = (from + 1 + rng.index(count - 1)) % count;
+        let crates = rng.between(1, heights[from] as i64 - 1) as usize;
+
+        heights[from] -= crates;
+        heights[to] += crates;
+        lines.push(format!("move {crates} from {} to {}", from + 1, to + 1));
+    }
+
+    return lines.join("\n");
+}
+
+/// `size` characters of noise from only three letters, so no marker can start there, followed by a start-of-message
+/// marker and some more characters.
+fn datastream(rng: &mut Rng, size: usize) -> String {
+    let mut marker = LOWERCASE.to_vec();
+    rng.shuffle(&mut marker);
+
+    let rest = rng.between(1, 10) as usize;
+
+    return letters(rng, b"xyz", size) + std::str::from_utf8(&marker[..14]).unwrap() + &letters(rng, LOWERCASE, rest);
+}
+
+struct Directory {
+    files: Vec<(String, u32)>,
+    directories: Vec<usize>,
+}
+
+/// Transcript of exploring a file system with `size` entries, using between 40,000,001 and 70,000,000 of the
+/// 70,000,000 available, so part two always has a directory to delete.
+fn terminal_output(rng: &mut Rng, size: usize) -> String {
+    let mut tree = vec![Directory { files: Vec::new(), directories: Vec::new() }];
+    let mut names = vec![String::from("/")];
+    let max_file_size = (40_000_000 / size.max(1)).max(1) as i64;
+    let mut used = 0;
+
+    for entry in 0..size {
+        let parent = rng.index(tree.len());
+        let len = rng.between(1, 5) as usize;
+        let name = format!("{}{entry}", letters(rng, LOWERCASE, len));
+
+        if rng.one_in(4) {
+            let directory = tree.len();
+            tree.push(Directory { files: Vec::new(), directories: Vec::new() });
+            tree[parent].directories.push(directory);
+            names.push(name);
+        } else {
+            let file_size = rng.between(1, max_file_size) as u32;
+            tree[parent].files.push((name + ".dat", file_size));
+            used += file_size;
+        }
+    }
+
+    let total = rng.between(40_000_001, 70_000_000) as u32;
+    tree[0].files.push((String::from("filler.bin"), total.saturating_sub(used).max(1)));
+
+    let mut lines = vec![String::from("$ cd /")];
+    explore(&tree, &names, 0, &mut lines);
+
+    return lines.join("\n");
+}
+
+fn explore(tree: &[Directory], names: &[String], directory: usize, lines: &mut Vec<String>) {
+    lines.push(String::from("$ ls"));
+    lines.extend(tree[directory].directories.iter().map(|d| format!("dir {}", names[*d])));
+    lines.extend(tree[directory].files.iter().map(|(name, size)| format!("{size} {name}")));
+
+    for child in &tree[directory].directories {
+        lines.push(format!("$ cd {}", names[*child]));
+        explore(tree, names, *child, lines);
+        lines.push(String::from("$ cd .."));
+    }
+}
+
+/// Square grid of `size` by `size` trees.
+fn tree_grid(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+
+    return (0..size).map(|_| letters(rng, b"0123456789", size)).join("\n");
+}
+
+/// `size` motions of up to 20 steps.
+fn rope_motions(rng: &mut Rng, size: usize) -> String {
+    return (0..size.max(1))
+        .map(|_| format!("{} {}", rng.pick(&["R", "L", "U", "D"]), rng.between(1, 20)))
+        .join("\n");
+}
+
+/// `size` instructions.
+fn cpu_program(rng: &mut Rng, size: usize) -> String {
+    return (0..size.max(1))
+        .map(|_| if rng.one_in(3) { String::from("noop") } else { format!("addx {}", rng.between(-20, 20)) })
+        .join("\n");
+}
+
+/// Two to eight monkeys holding `size` items in total.
+///
+/// Operations only add or multiply by two or three, since anything that grows faster could overflow the worry levels
+/// in part one, where they are never reduced by the product of the divisors.
+fn monkeys(rng: &mut Rng, size: usize) -> String {
+    let count = rng.between(2, 8) as usize;
+    let mut divisors = vec![2, 3, 5, 7, 11, 13, 17, 19, 23];
+    rng.shuffle(&mut divisors);
+
+    let mut items = vec![1; count];
+    for _ in count..size {
+        items[rng.index(count)] += 1;
+    }
+
+    return (0..count)
+        .map(|monkey| {
+            let operation = match rng.between(0, 2) {
+                0 => format!("old + {}", rng.between(1, 8)),
+                _ => format!("old * {}", rng.between(2, 3)),
+            };
+            let if_true = (monkey + 1 + rng.index(count - 1)) % count;
+            let if_false = (monkey + 1 + rng.index(count - 1)) % count;
+
+            return [
+                format!("Monkey {monkey}:"),
+                format!("  Starting items: {}", (0..items[monkey]).map(|_| rng.between(1, 99)).join(", ")),
+                format!("  Operation: new = {operation}"),
+                format!("  Test: divisible by {}", divisors[monkey]),
+                format!("    If true: throw to monkey {if_true}"),
+                format!("    If false: throw to monkey {if_false}"),
+            ].join("\n");
+        })
+        .join("\n\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::y2022::YEAR;
+
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_reproducible() {
+        assert_eq!(YEAR.generate(7, 1, 50), YEAR.generate(7, 1, 50));
+        assert_ne!(YEAR.generate(7, 1, 50), YEAR.generate(7, 2, 50));
+        assert_eq!(None, YEAR.generate(25, 1, 50));
+    }
+
+    #[rstest]
+    fn generated_inputs_are_solvable(
+        #[values(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)] day: u8,
+        #[values(0, 1, 17)] size: usize,
+    ) {
+        let solution = YEAR.solution(day).unwrap();
+
+        for seed in 0..5 {
+            let input = YEAR.generate(day, seed, size).unwrap();
+
+            for part in [1, 2] {
+                if let Err(error) = solution.part(part, &input) {
+                    panic!("Part {part} failed for seed {seed}: {error}\n{input}");
+                }
+            }
+        }
+    }
+
+    #[rstest]
+    fn large_generated_inputs_are_solvable(#[values(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)] day: u8) {
+        let solution = YEAR.solution(day).unwrap();
+        let size = large_size(day).unwrap();
+
+        for seed in 0..2 {
+            let input = YEAR.generate(day, seed, size).unwrap();
+
+            for part in [1, 2] {
+                if let Err(error) = solution.part(part, &input) {
+                    panic!("Part {part} failed for seed {seed} and size {size}: {error}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::differential::Pair;
+use crate::params::Configurable;
+use crate::solution::Solution;
+use crate::stream::Streaming;
+use crate::visualize::Visualization;
+use crate::year::Year;
+
+pub mod generate;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+
+static SOLUTIONS: [&dyn Solution; 11] = [
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+];
+
+static PAIRS: [Pair; 11] = [
+    Pair { reference: &day01::Day01, optimized: &day01::Optimized },
+    Pair { reference: &day02::Day02, optimized: &day02::Optimized },
+    Pair { reference: &day03::Day03, optimized: &day03::Optimized },
+    Pair { reference: &day04::Day04, optimized: &day04::Optimized },
+    Pair { reference: &day05::Day05, optimized: &day05::Optimized },
+    Pair { reference: &day06::Day06, optimized: &day06::Optimized },
+    Pair { reference: &day07::Day07, optimized: &day07::Optimized },
+    Pair { reference: &day08::Day08, optimized: &day08::Optimized },
+    Pair { reference: &day09::Day09, optimized: &day09::Optimized },
+    Pair { reference: &day10::Day10, optimized: &day10::Optimized },
+    Pair { reference: &day11::Day11, optimized: &day11::Optimized },
+];
+
+static VISUALIZATIONS: [&dyn Visualization; 4] = [
+    &day05::Day05,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+];
+
+static STREAMS: [&dyn Streaming; 8] = [
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day06::Day06,
+    &day07::Day07,
+    &day09::Day09,
+    &day10::Day10,
+];
+
+static CONFIGURABLES: [&dyn Configurable; 6] = [
+    &day01::Day01,
+    &day06::Day06,
+    &day07::Day07,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+];
+
+pub static YEAR: Year = Year {
+    year: 2022,
+    solutions: &SOLUTIONS,
+    pairs: &PAIRS,
+    visualizations: &VISUALIZATIONS,
+    streams: &STREAMS,
+    configurables: &CONFIGURABLES,
+    generator: generate::generator,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A day has a pair once it has an optimized implementation, which a freshly scaffolded day does not.
+    #[test]
+    fn every_pair_is_of_a_registered_day() {
+        assert!(PAIRS.iter().all(|p| SOLUTIONS.iter().any(|s| s.day() == p.day())));
+        assert!(PAIRS.iter().all(|p| p.reference.day() == p.optimized.day()));
+    }
+}
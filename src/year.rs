@@ -0,0 +1,50 @@
+use crate::differential::Pair;
+use crate::generate::{Generator, Rng};
+use crate::params::Configurable;
+use crate::solution::Solution;
+use crate::stream::Streaming;
+use crate::visualize::Visualization;
+
+/// The puzzles of one year of Advent of Code, along with everything the runner offers for them.
+///
+/// Every year lives in its own `yYYYY` module, which defines the registries of its days in a `YEAR` static.
+pub struct Year {
+    pub year: u16,
+    pub solutions: &'static [&'static dyn Solution],
+    pub pairs: &'static [Pair],
+    pub visualizations: &'static [&'static dyn Visualization],
+    pub streams: &'static [&'static dyn Streaming],
+    pub configurables: &'static [&'static dyn Configurable],
+    pub generator: fn(u8) -> Option<Generator>,
+}
+
+impl Year {
+    pub fn solution(&self, day: u8) -> Option<&'static dyn Solution> {
+        return self.solutions.iter().find(|s| s.day() == day).copied();
+    }
+
+    pub fn pair(&self, day: u8) -> Option<&'static Pair> {
+        return self.pairs.iter().find(|pair| pair.day() == day);
+    }
+
+    pub fn visualization(&self, day: u8) -> Option<&'static dyn Visualization> {
+        return self.visualizations.iter().find(|v| v.day() == day).copied();
+    }
+
+    pub fn stream(&self, day: u8) -> Option<&'static dyn Streaming> {
+        return self.streams.iter().find(|s| s.day() == day).copied();
+    }
+
+    pub fn configurable(&self, day: u8) -> Option<&'static dyn Configurable> {
+        return self.configurables.iter().find(|c| c.day() == day).copied();
+    }
+
+    pub fn generator(&self, day: u8) -> Option<Generator> {
+        return (self.generator)(day);
+    }
+
+    /// Generates the input of a day from a seed, so the same seed and size always yield the same input.
+    pub fn generate(&self, day: u8, seed: u64, size: usize) -> Option<String> {
+        return Some(self.generator(day)?(&mut Rng::new(seed), size));
+    }
+}
@@ -0,0 +1,73 @@
+use std::fs;
+use std::path::Path;
+
+use itertools::Itertools;
+use rstest::rstest;
+
+use advent_of_rust::fixtures;
+use advent_of_rust::fuzz;
+use advent_of_rust::solution::Error;
+use advent_of_rust::y2022::YEAR;
+
+/// Inputs of a day: its examples, its fuzz inputs, a few generated ones and all of them broken in several places.
+fn inputs(day: u8) -> Vec<String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut inputs: Vec<String> = fixtures::discover(&root.join(fixtures::DIRECTORY))
+        .unwrap()
+        .iter()
+        .filter(|fixture| fixture.year == YEAR.year && fixture.day == day)
+        .map(|fixture| fixture.read().unwrap().0)
+        .collect();
+
+    for path in fuzz::inputs(&root.join(fuzz::DIRECTORY), &fuzz::target(YEAR.year, day)).unwrap() {
+        inputs.extend(String::from_utf8(fs::read(path).unwrap()));
+    }
+
+    for seed in 0..5 {
+        inputs.push(YEAR.generate(day, seed, 10).unwrap());
+    }
+
+    let broken: Vec<String> = inputs.iter()
+        .filter(|input| !input.is_empty())
+        .flat_map(|input| {
+            let every_third_line = input.lines()
+                .enumerate()
+                .map(|(index, line)| if index % 3 == 1 { format!("{line}?") } else { String::from(line) })
+                .join("\n");
+
+            [format!("?{input}"), format!("{}\n?", input.trim_end()), every_third_line]
+        })
+        .collect();
+    inputs.extend(broken);
+
+    return inputs;
+}
+
+/// Checks the inputs of a day and parses them, see [advent_of_rust::solution::Puzzle::check]. Checking finds no
+/// problem exactly if parsing succeeds, and otherwise the problem parsing stops at is among those found.
+#[rstest]
+#[case(1)]
+#[case(2)]
+#[case(3)]
+#[case(4)]
+#[case(5)]
+#[case(6)]
+#[case(7)]
+#[case(8)]
+#[case(9)]
+#[case(10)]
+#[case(11)]
+fn check_agrees_with_parse(#[case] day: u8) {
+    let solution = YEAR.solution(day).unwrap();
+
+    for input in inputs(day) {
+        let problems = solution.check(&input);
+
+        match solution.part(1, &input) {
+            Err(Error::Parse(error)) => assert!(problems.contains(&error), "{input:?}: {error:?} not in {problems:?}"),
+            _ => assert!(problems.is_empty(), "{input:?}: parsed, but found {problems:?}"),
+        }
+
+        assert!(problems.iter().tuple_windows().all(|(a, b)| (a.line, a.column) < (b.line, b.column)), "{problems:?}");
+    }
+}
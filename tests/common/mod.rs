@@ -0,0 +1,106 @@
+use std::env;
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        return self.headers.iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str());
+    }
+}
+
+/// Stand-in for adventofcode.com, answering every request on localhost with the response of `handler`.
+pub struct MockServer {
+    pub url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockServer {
+    pub fn start(handler: impl Fn(&Request) -> (u16, String) + Send + 'static) -> MockServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let request = read_request(&mut stream);
+                let (status, body) = handler(&request);
+                recorded.lock().unwrap().push(request);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Mock\r\nContent-Type: text/plain\r\nContent-Length: {}\r\n\
+                        Connection: close\r\n\r\n{body}",
+                    body.len()
+                ).unwrap();
+            }
+        });
+
+        return MockServer { url, requests };
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        return self.requests.lock().unwrap().clone();
+    }
+}
+
+fn read_request(stream: &mut TcpStream) -> Request {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+
+    let mut parts = line.split_whitespace();
+    let method = String::from(parts.next().unwrap());
+    let path = String::from(parts.next().unwrap());
+
+    let mut headers = Vec::new();
+
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+
+        match line.trim_end().split_once(": ") {
+            Some((key, value)) => headers.push((String::from(key), String::from(value))),
+            None => break,
+        }
+    }
+
+    let mut request = Request { method, path, headers, body: String::new() };
+    let length = request.header("Content-Length").map_or(0, |length| length.parse().unwrap());
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).unwrap();
+    request.body = String::from_utf8(body).unwrap();
+
+    return request;
+}
+
+/// Creates an empty directory that is unique to the calling test.
+pub fn temp_dir(name: &str) -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let directory = env::temp_dir().join(format!(
+        "advent-of-rust-{name}-{}-{}",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let _ = fs::remove_dir_all(&directory);
+    fs::create_dir_all(&directory).unwrap();
+
+    return directory;
+}
@@ -0,0 +1,40 @@
+use rstest::rstest;
+
+use advent_of_rust::y2022;
+
+/// Runs the reference and the optimized implementation of every day on generated inputs, see
+/// [advent_of_rust::differential::Pair].
+///
+/// Day 11 simulates 10,000 rounds for part two, so it is checked on fewer seeds.
+#[rstest]
+#[case(1, 20)]
+#[case(2, 20)]
+#[case(3, 20)]
+#[case(4, 20)]
+#[case(5, 20)]
+#[case(6, 20)]
+#[case(7, 20)]
+#[case(8, 20)]
+#[case(9, 20)]
+#[case(10, 20)]
+#[case(11, 4)]
+fn implementations_agree(#[case] day: u8, #[case] seeds: u64) {
+    let pair = y2022::YEAR.pair(day).unwrap();
+    let generator = y2022::YEAR.generator(day).unwrap();
+
+    if let Err(counterexample) = pair.check(generator, 0..seeds, &[0, 1, 5, 20]) {
+        panic!("{counterexample}");
+    }
+}
+
+/// Same as [implementations_agree] on a few inputs of the size given by [y2022::generate::large_size].
+#[rstest]
+fn implementations_agree_on_large_inputs(#[values(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)] day: u8) {
+    let pair = y2022::YEAR.pair(day).unwrap();
+    let generator = y2022::YEAR.generator(day).unwrap();
+    let size = y2022::generate::large_size(day).unwrap();
+
+    if let Err(counterexample) = pair.check(generator, 0..2, &[size]) {
+        panic!("{counterexample}");
+    }
+}
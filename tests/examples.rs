@@ -0,0 +1,13 @@
+use std::path::Path;
+
+use advent_of_rust::fixtures;
+
+/// Checks the answers of every fixture in `examples/`, see [fixtures::Fixture].
+#[test]
+fn examples() {
+    let fixtures = fixtures::discover(&Path::new(env!("CARGO_MANIFEST_DIR")).join(fixtures::DIRECTORY)).unwrap();
+    let problems: Vec<String> = fixtures.iter().flat_map(|fixture| fixture.check()).collect();
+
+    assert!(!fixtures.is_empty(), "No fixtures found");
+    assert!(problems.is_empty(), "{} fixture(s) failed:\n{}", problems.len(), problems.join("\n"));
+}
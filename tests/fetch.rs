@@ -0,0 +1,124 @@
+use std::fs;
+use std::process::Command;
+
+use advent_of_rust::client::{Client, ClientError, USER_AGENT};
+use advent_of_rust::input::{download, Download};
+
+mod common;
+
+use common::{temp_dir, MockServer};
+
+fn input_server() -> MockServer {
+    return MockServer::start(|request| match request.path.as_str() {
+        "/2022/day/6/input" => (200, String::from("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n")),
+        "/2022/day/25/input" => (404, String::from("Please don't repeatedly request this endpoint before it unlocks!")),
+        _ => (500, String::new()),
+    });
+}
+
+#[test]
+fn downloads_input_with_session_cookie_and_user_agent() {
+    let server = input_server();
+    let directory = temp_dir("fetch");
+
+    let result = download(&Client::new(&server.url, Some(String::from("abc123"))), 2022, 6, &directory);
+
+    assert_eq!(Download::Downloaded(directory.join("day06.txt")), result.unwrap());
+    assert_eq!("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n", fs::read_to_string(directory.join("day06.txt")).unwrap());
+
+    let requests = server.requests();
+    assert_eq!(1, requests.len());
+    assert_eq!("GET", requests[0].method);
+    assert_eq!(Some("session=abc123"), requests[0].header("Cookie"));
+    assert_eq!(Some(USER_AGENT), requests[0].header("User-Agent"));
+}
+
+#[test]
+fn never_downloads_existing_input_again() {
+    let server = input_server();
+    let directory = temp_dir("fetch");
+    fs::write(directory.join("day06.txt"), "abcd").unwrap();
+
+    let result = download(&Client::new(&server.url, Some(String::from("abc123"))), 2022, 6, &directory);
+
+    assert_eq!(Download::Cached(directory.join("day06.txt")), result.unwrap());
+    assert_eq!("abcd", fs::read_to_string(directory.join("day06.txt")).unwrap());
+    assert_eq!(0, server.requests().len());
+}
+
+#[test]
+fn replaces_empty_placeholder() {
+    let server = input_server();
+    let directory = temp_dir("fetch");
+    fs::write(directory.join("day06.txt"), "").unwrap();
+
+    let result = download(&Client::new(&server.url, Some(String::from("abc123"))), 2022, 6, &directory);
+
+    assert_eq!(Download::Downloaded(directory.join("day06.txt")), result.unwrap());
+    assert_eq!("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n", fs::read_to_string(directory.join("day06.txt")).unwrap());
+}
+
+#[test]
+fn missing_session() {
+    let server = input_server();
+    let directory = temp_dir("fetch");
+
+    let result = download(&Client::new(&server.url, None), 2022, 6, &directory);
+
+    assert!(matches!(result, Err(ClientError::MissingSession)));
+    assert_eq!(0, server.requests().len());
+}
+
+#[test]
+fn rejected_session() {
+    let message = "Puzzle inputs differ by user.  Please log in to get your puzzle input.";
+    let server = MockServer::start(move |_| (400, String::from(message)));
+    let directory = temp_dir("fetch");
+
+    let result = download(&Client::new(&server.url, Some(String::from("expired"))), 2022, 6, &directory);
+
+    assert!(matches!(result, Err(ClientError::Unauthorized)));
+    assert!(!directory.join("day06.txt").exists());
+}
+
+#[test]
+fn puzzle_not_unlocked_yet() {
+    let server = input_server();
+    let directory = temp_dir("fetch");
+
+    let result = download(&Client::new(&server.url, Some(String::from("abc123"))), 2022, 25, &directory);
+
+    assert!(matches!(result, Err(ClientError::NotAvailable(25))));
+    assert_eq!(0, fs::read_dir(&directory).unwrap().count());
+}
+
+#[test]
+fn fetch_command_reads_session_from_config_file() {
+    let server = input_server();
+    let directory = temp_dir("fetch");
+    let config = directory.join("config.toml");
+    fs::write(&config, "session = \"from-config\"").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["fetch", "6", "--config", config.to_str().unwrap()])
+        .env("AOC_INPUT_DIR", directory.join("input"))
+        .env("AOC_BASE_URL", &server.url)
+        .env_remove("AOC_SESSION")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(directory.join("input/2022/day06.txt").exists());
+    assert_eq!(Some("session=from-config"), server.requests()[0].header("Cookie"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["fetch", "6", "--config", config.to_str().unwrap()])
+        .env("AOC_INPUT_DIR", directory.join("input"))
+        .env("AOC_BASE_URL", &server.url)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("already exists"));
+    assert_eq!(1, server.requests().len());
+}
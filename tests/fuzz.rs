@@ -0,0 +1,39 @@
+use std::fs;
+use std::path::Path;
+
+use rstest::rstest;
+
+use advent_of_rust::fuzz;
+use advent_of_rust::y2022::YEAR;
+
+/// Runs the corpus and the regressions of the fuzz target of every day, on the reference and the optimized
+/// implementation. No input may make a day panic, see [advent_of_rust::fuzz::solve].
+#[rstest]
+#[case(1)]
+#[case(2)]
+#[case(3)]
+#[case(4)]
+#[case(5)]
+#[case(6)]
+#[case(7)]
+#[case(8)]
+#[case(9)]
+#[case(10)]
+#[case(11)]
+fn fuzz_inputs_do_not_panic(#[case] day: u8) {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join(fuzz::DIRECTORY);
+    let inputs = fuzz::inputs(&directory, &fuzz::target(YEAR.year, day)).unwrap();
+    let pair = YEAR.pair(day).unwrap();
+
+    assert!(!inputs.is_empty(), "No fuzz inputs for day {day}");
+
+    for path in inputs {
+        let data = fs::read(&path).unwrap();
+
+        for solution in [pair.reference, pair.optimized] {
+            if let Some(message) = fuzz::panic(solution, &data) {
+                panic!("{} panicked: {message}", path.display());
+            }
+        }
+    }
+}
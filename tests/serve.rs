@@ -0,0 +1,108 @@
+use std::io::Read;
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use advent_of_rust::serve::{Limits, Server};
+
+/// Starts a server on a free port of localhost and returns its URL.
+fn start(limits: Limits) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    thread::spawn(move || Server::new(advent_of_rust::years(), limits).serve(listener));
+
+    return url;
+}
+
+fn limits() -> Limits {
+    return Limits {
+        max_body: 1024 * 1024,
+        timeout: Duration::from_secs(10),
+        request_timeout: Duration::from_secs(10),
+        max_running: 4,
+        max_connections: 8,
+    };
+}
+
+/// Status and JSON body of a response, whether it succeeded or not.
+fn response(result: Result<ureq::Response, ureq::Error>) -> (u16, Value) {
+    let response = match result {
+        Ok(response) => response,
+        Err(ureq::Error::Status(_, response)) => response,
+        Err(error) => panic!("{error}"),
+    };
+
+    return (response.status(), serde_json::from_str(&response.into_string().unwrap()).unwrap());
+}
+
+#[test]
+fn solves_parts_over_http() {
+    let url = start(limits());
+    let input = include_str!("../examples/2022/day07/example.txt");
+
+    let (status, body) = response(ureq::post(&format!("{url}/2022/day/7/part/2")).send_string(input));
+
+    assert_eq!(200, status);
+    assert_eq!(json!(24933642), body["answer"]);
+    assert_eq!(json!(2022), body["year"]);
+
+    let (status, body) = response(ureq::post(&format!("{url}/2022/day/10/part/2")).send_string(""));
+
+    assert_eq!(200, status);
+    assert!(body["answer"].is_array());
+
+    let (status, body) = response(ureq::get(&format!("{url}/days")).call());
+
+    assert_eq!(200, status);
+    assert_eq!(json!(2022), body[0]["year"]);
+}
+
+#[test]
+fn reports_errors_as_json() {
+    let url = start(limits());
+
+    let (status, body) = response(ureq::post(&format!("{url}/2022/day/7/part/1")).send_string("$ cd /\n$ rm -rf\n"));
+
+    assert_eq!(422, status);
+    assert_eq!(json!("parse"), body["kind"]);
+    assert_eq!(json!(2), body["line"]);
+
+    let (status, body) = response(ureq::post(&format!("{url}/2022/day/26/part/1")).send_string(""));
+
+    assert_eq!(404, status);
+    assert_eq!(json!("Day 26 of 2022 is not implemented"), body["error"]);
+}
+
+#[test]
+fn rejects_large_inputs_and_gives_up_on_slow_parts() {
+    let url = start(Limits { max_body: 1000, timeout: Duration::from_millis(1), max_running: 1, ..limits() });
+    let input = include_str!("../examples/2022/day11/example.txt");
+
+    let (status, _) = response(ureq::post(&format!("{url}/2022/day/1/part/1")).send_string(&"1\n".repeat(501)));
+
+    assert_eq!(413, status);
+
+    let (status, body) = response(ureq::post(&format!("{url}/2022/day/11/part/2")).send_string(input));
+
+    assert_eq!(504, status);
+    assert_eq!(json!("timeout"), body["kind"]);
+}
+
+#[test]
+fn answers_connections_beyond_the_limit_as_busy() {
+    let url = start(Limits { max_connections: 1, ..limits() });
+    let address = url.trim_start_matches("http://");
+
+    // The first connection is accepted before the second one and holds its slot as long as it sends nothing
+    let open = TcpStream::connect(address).unwrap();
+    let mut rejected = TcpStream::connect(address).unwrap();
+    let mut response = String::new();
+    rejected.read_to_string(&mut response).unwrap();
+
+    assert!(response.starts_with("HTTP/1.1 503 Service Unavailable\r\n"));
+    assert!(response.contains("\"kind\": \"busy\""));
+    drop(open);
+}
@@ -0,0 +1,64 @@
+use std::path::Path;
+
+use rstest::rstest;
+
+use advent_of_rust::fixtures;
+use advent_of_rust::solution::Error;
+use advent_of_rust::y2022::YEAR;
+
+/// Inputs of a day: its examples, generated inputs of a few sizes and some of them broken in a way the parser rejects.
+fn inputs(day: u8) -> Vec<String> {
+    let mut inputs: Vec<String> = fixtures::discover(&Path::new(env!("CARGO_MANIFEST_DIR")).join(fixtures::DIRECTORY))
+        .unwrap()
+        .iter()
+        .filter(|fixture| fixture.year == YEAR.year && fixture.day == day)
+        .map(|fixture| fixture.read().unwrap().0)
+        .collect();
+
+    for seed in 0..10 {
+        for size in [0, 1, 5, 50] {
+            inputs.push(YEAR.generate(day, seed, size).unwrap());
+        }
+    }
+
+    let broken: Vec<String> = inputs.iter()
+        .filter(|input| !input.is_empty())
+        .flat_map(|input| [format!("?{input}"), format!("{}\n?", input.trim_end())])
+        .collect();
+    inputs.extend(broken);
+
+    return inputs;
+}
+
+/// Streams the inputs of a day and compares the results with the ones of the solution of the day, see
+/// [advent_of_rust::stream::Stream].
+///
+/// Both have to reject the same invalid inputs at the same position, while errors of inputs without answer may
+/// have a different message.
+#[rstest]
+#[case(1)]
+#[case(2)]
+#[case(3)]
+#[case(4)]
+#[case(6)]
+#[case(7)]
+#[case(9)]
+#[case(10)]
+fn streamed_answers_match(#[case] day: u8) {
+    let stream = YEAR.stream(day).unwrap();
+    let solution = YEAR.solution(day).unwrap();
+
+    for input in inputs(day) {
+        for part in [1, 2] {
+            let expected = solution.part(part, &input);
+            let actual = stream.stream(part, &mut input.as_bytes());
+
+            match (&expected, &actual) {
+                (Ok(expected), Ok(actual)) if expected == actual => {}
+                (Err(Error::Parse(expected)), Err(Error::Parse(actual))) if expected == actual => {}
+                (Err(Error::Unsolvable(_)), Err(Error::Unsolvable(_))) => {}
+                _ => panic!("Part {part} of {input:?}: expected {expected:?}, got {actual:?}"),
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+use advent_of_rust::client::USER_AGENT;
+
+mod common;
+
+use common::{temp_dir, MockServer};
+
+fn page(article: &str) -> String {
+    return format!("<!DOCTYPE html>\n<html><body><main>\n<article><p>{article}</p></article>\n</main></body></html>");
+}
+
+fn answer_server(article: &'static str) -> MockServer {
+    return MockServer::start(move |request| match (request.method.as_str(), request.path.as_str()) {
+        ("POST", "/2022/day/6/answer") => (200, page(article)),
+        _ => (404, String::new()),
+    });
+}
+
+/// Submits part one of day 6 for an input whose answer is 7.
+fn submit(server: &MockServer, directory: &Path) -> Output {
+    return Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["submit", "6", "1", "--input-text", "mjqjpqmgbljsphdztnvjfqwrcgsmlb"])
+        .arg("--submissions")
+        .arg(directory.join("submissions.toml"))
+        .env("AOC_SESSION", "abc123")
+        .env("AOC_BASE_URL", &server.url)
+        .output()
+        .unwrap();
+}
+
+fn stderr(output: &Output) -> String {
+    return String::from_utf8_lossy(&output.stderr).into_owned();
+}
+
+#[test]
+fn submits_correct_answer() {
+    let server = answer_server("That's the right answer!  You are one gold star closer to saving your vacation.");
+    let directory = temp_dir("submit");
+
+    let output = submit(&server, &directory);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!("Day 06 Part One: submitted 7, correct\n", String::from_utf8_lossy(&output.stdout));
+
+    let requests = server.requests();
+    assert_eq!(1, requests.len());
+    assert_eq!("level=1&answer=7", requests[0].body);
+    assert_eq!(Some("session=abc123"), requests[0].header("Cookie"));
+    assert_eq!(Some(USER_AGENT), requests[0].header("User-Agent"));
+
+    let submissions = fs::read_to_string(directory.join("submissions.toml")).unwrap();
+    assert!(submissions.contains("[[day06.part_one]]\nanswer = 7\noutcome = \"correct\""), "{submissions}");
+}
+
+#[test]
+fn refuses_to_resubmit_wrong_answer() {
+    let server = answer_server(
+        "That's not the right answer; your answer is too high.  Please wait one minute before trying again."
+    );
+    let directory = temp_dir("submit");
+
+    let output = submit(&server, &directory);
+
+    assert!(!output.status.success());
+    assert_eq!("Day 06 Part One: submitted 7, incorrect, too high\n", String::from_utf8_lossy(&output.stdout));
+
+    let output = submit(&server, &directory);
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("7 was already submitted and is incorrect, too high"), "{}", stderr(&output));
+    assert_eq!(1, server.requests().len());
+}
+
+#[test]
+fn throttles_after_being_asked_to_wait() {
+    let server = answer_server(
+        "You gave an answer too recently; you have to wait after submitting an answer before trying again.  \
+            You have 5m 0s left to wait."
+    );
+    let directory = temp_dir("submit");
+
+    let output = submit(&server, &directory);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("wait 300s before submitting again"));
+
+    let output = submit(&server, &directory);
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("before submitting another answer"), "{}", stderr(&output));
+    assert_eq!(1, server.requests().len());
+}
+
+#[test]
+fn unrecognized_response_is_not_recorded() {
+    let server = answer_server("Something unexpected happened.");
+    let directory = temp_dir("submit");
+
+    let output = submit(&server, &directory);
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("Unable to find the outcome"));
+    assert!(!directory.join("submissions.toml").exists());
+}